@@ -0,0 +1,96 @@
+//! Repeated timing of parsing and solving, for steadier numbers than a single run gives.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How many times a measured step is run.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    /// Untimed runs done before measuring.
+    pub warmup: usize,
+    /// Fixed number of timed runs, when unset runs are repeated until `budget` is spent.
+    pub runs: Option<usize>,
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: None,
+            budget: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Summary of the timings of a single step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation, zero for a single run.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`, `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let runs = samples.len();
+        if runs == 0 {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let secs: Vec<_> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = match runs {
+            1 => 0.0,
+            _ => secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64,
+        };
+
+        Some(Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Times `f` as described by `config`.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+
+    loop {
+        let run_start = Instant::now();
+        black_box(f());
+        samples.push(run_start.elapsed());
+
+        let done = match config.runs {
+            Some(runs) => samples.len() >= runs,
+            None => start.elapsed() >= config.budget,
+        };
+
+        if done {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples).expect("at least one run is always timed")
+}
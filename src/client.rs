@@ -0,0 +1,58 @@
+//! HTTP access to the Advent of Code website, shared by input downloads and answer submission.
+//!
+//! Configured through the environment:
+//!
+//! - `AOC_SESSION`: session cookie of a logged in Advent of Code account, required
+//! - `AOC_BASE_URL`: where to send requests, [`DEFAULT_BASE_URL`] by default
+//! - `AOC_USER_AGENT`: overrides [`DEFAULT_USER_AGENT`]
+
+use std::env;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool and a maintainer to contact, as the puzzle authors ask automated requests to.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "github.com/tranzystorek-io/aoc2022-rust by ",
+    env!("CARGO_PKG_AUTHORS")
+);
+
+/// Logged in session with the website, or anything serving the same paths.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Self {
+        let agent = ureq::AgentBuilder::new().user_agent(user_agent).build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    /// Configures the client from `AOC_*` environment variables, `None` without a session token.
+    pub fn from_env() -> Option<Self> {
+        let session = env::var("AOC_SESSION").ok().filter(|s| !s.is_empty())?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        let user_agent =
+            env::var("AOC_USER_AGENT").unwrap_or_else(|_| DEFAULT_USER_AGENT.to_owned());
+
+        Some(Self::new(&base_url, &session, &user_agent))
+    }
+
+    /// Full URL of the puzzle page `path` of day `day`, e.g. `input`.
+    pub fn day_url(&self, day: u8, path: &str) -> String {
+        format!("{}/2022/day/{day}/{path}", self.base_url)
+    }
+
+    /// Request to `url` carrying the session cookie.
+    pub fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
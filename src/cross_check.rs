@@ -0,0 +1,79 @@
+//! Running every implementation of a day on the same input and comparing their answers.
+
+use std::fmt::{self, Display};
+
+use anyhow::Context;
+
+use crate::days;
+use crate::solution::Part;
+
+/// An answer of a variant that differs from the answer of the main solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    pub day: u8,
+    pub part: Part,
+    pub variant: &'static str,
+    /// Answer of the main solution.
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {} part {}: {} answered {}, expected {}",
+            self.day, self.part, self.variant, self.actual, self.expected
+        )
+    }
+}
+
+/// Solves `parts` of `input` with every implementation of `day` with `params` applied,
+/// returning the answers of variants that differ from the main solution.
+///
+/// Fails if the input does not parse or has no answer, or only with some of the implementations.
+#[anyhoo::anyhoo]
+pub fn cross_check(
+    day: u8,
+    input: &str,
+    parts: &[Part],
+    params: &[(String, String)],
+) -> Vec<Divergence> {
+    let (main, variants) = match days::implementations(day).split_first() {
+        Some((&main, variants)) => (main, variants.to_vec()),
+        None => anyhow::bail!("No solution registered for day {day}"),
+    };
+
+    let main = main.with_params(params)?;
+    let parsed = main.parse_dyn(input)?;
+    let expected = parts
+        .iter()
+        .map(|&part| main.solve_dyn(parsed.as_ref(), part))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let mut divergences = vec![];
+
+    for variant in variants {
+        let variant = variant.with_params(params)?;
+        let parsed = variant
+            .parse_dyn(input)
+            .with_context(|| format!("Variant {} failed to parse", variant.variant()))?;
+
+        for (&part, expected) in parts.iter().zip(&expected) {
+            let actual = variant
+                .solve_dyn(parsed.as_ref(), part)
+                .with_context(|| format!("Variant {} failed to answer", variant.variant()))?;
+
+            if &actual != expected {
+                divergences.push(Divergence {
+                    day,
+                    part,
+                    variant: variant.variant(),
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+    }
+
+    divergences
+}
@@ -0,0 +1,60 @@
+//! Day 1: Calorie Counting
+
+use crate::parse::{self, ParseContext, ParseError};
+use crate::solution::Solution;
+
+/// Solver for both parts of day 1.
+#[derive(Clone, Debug)]
+pub struct Day01;
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Vec<usize>> {
+    let result = parse::paragraphs(input)
+        .map(|set| {
+            set.lines()
+                .map(|n| n.parse().or_parse_error(input, n, "invalid calorie count"))
+                .collect()
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    if result.is_empty() {
+        anyhow::bail!(ParseError::at_end(input, "expected calorie counts"));
+    }
+
+    result
+}
+
+/// Total calories carried by each elf.
+pub fn sums(calories_carried: &[Vec<usize>]) -> impl Iterator<Item = usize> + '_ {
+    calories_carried.iter().map(|elf| elf.iter().sum::<usize>())
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input = Vec<Vec<usize>>;
+    type Answer1 = usize;
+    type Answer2 = anyhow::Result<usize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, calories_carried: &Self::Input) -> usize {
+        // parsing makes sure there is at least one elf
+        sums(calories_carried).max().unwrap()
+    }
+
+    fn part2(&self, calories_carried: &Self::Input) -> anyhow::Result<usize> {
+        let mut sums: Vec<_> = sums(calories_carried).collect();
+
+        sums.sort_unstable();
+
+        let [.., a, b, c] = sums[..] else {
+            anyhow::bail!("Fewer than three elves carry calories");
+        };
+
+        Ok(a + b + c)
+    }
+}
@@ -0,0 +1,158 @@
+//! Day 2: Rock Paper Scissors
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::Solution;
+
+/// Solver for both parts of day 2.
+#[derive(Clone, Debug)]
+pub struct Day02;
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<(Move, Code)> {
+    input
+        .lines()
+        .map(|l| {
+            let (their, mine) =
+                l.split_once(' ')
+                    .or_parse_error(input, l, "expected two space-separated codes")?;
+
+            let their = match their {
+                "A" => Move::R,
+                "B" => Move::P,
+                "C" => Move::S,
+                _ => return Err(ParseError::at(input, their, "unknown opponent move")),
+            };
+            let mine = match mine {
+                "X" => Code::X,
+                "Y" => Code::Y,
+                "Z" => Code::Z,
+                _ => return Err(ParseError::at(input, mine, "unknown response code")),
+            };
+
+            Ok((their, mine))
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+/// A hand shape.
+#[derive(Clone, Copy)]
+pub enum Move {
+    R,
+    P,
+    S,
+}
+
+/// The result of a round, from our side.
+#[derive(Clone, Copy)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// The second column of the strategy guide, read differently by each part.
+#[derive(Clone, Copy)]
+pub enum Code {
+    X,
+    Y,
+    Z,
+}
+
+impl Move {
+    /// Points for playing this shape.
+    pub fn score(&self) -> usize {
+        match self {
+            Self::R => 1,
+            Self::P => 2,
+            Self::S => 3,
+        }
+    }
+
+    /// Points for the outcome of playing this shape against `other`.
+    pub fn spar(&self, other: &Self) -> usize {
+        match (self, other) {
+            (Self::R, Self::S) | (Self::P, Self::R) | (Self::S, Self::P) => 6,
+            (Self::R, Self::R) | (Self::P, Self::P) | (Self::S, Self::S) => 3,
+            _ => 0,
+        }
+    }
+
+    /// The shape to play against this one to end up with `outcome`.
+    pub fn predict(&self, outcome: &Outcome) -> Self {
+        match (self, outcome) {
+            (Self::R, Outcome::Draw) | (Self::P, Outcome::Lose) | (Self::S, Outcome::Win) => {
+                Self::R
+            }
+            (Self::P, Outcome::Draw) | (Self::R, Outcome::Win) | (Self::S, Outcome::Lose) => {
+                Self::P
+            }
+            _ => Self::S,
+        }
+    }
+}
+
+impl Outcome {
+    /// Points for this outcome.
+    pub fn score(&self) -> usize {
+        match self {
+            Self::Lose => 0,
+            Self::Draw => 3,
+            Self::Win => 6,
+        }
+    }
+}
+
+impl Code {
+    /// Part 1 reading: the shape we play.
+    pub fn as_move(&self) -> Move {
+        match self {
+            Self::X => Move::R,
+            Self::Y => Move::P,
+            Self::Z => Move::S,
+        }
+    }
+
+    /// Part 2 reading: the outcome we need.
+    pub fn as_outcome(&self) -> Outcome {
+        match self {
+            Self::X => Outcome::Lose,
+            Self::Y => Outcome::Draw,
+            Self::Z => Outcome::Win,
+        }
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input = Vec<(Move, Code)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, strategy: &Self::Input) -> usize {
+        strategy
+            .iter()
+            .map(|(their, code)| {
+                let mine = code.as_move();
+
+                mine.score() + mine.spar(their)
+            })
+            .sum::<usize>()
+    }
+
+    fn part2(&self, strategy: &Self::Input) -> usize {
+        strategy
+            .iter()
+            .map(|(their, code)| {
+                let outcome = code.as_outcome();
+
+                outcome.score() + their.predict(&outcome).score()
+            })
+            .sum::<usize>()
+    }
+}
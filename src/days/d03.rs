@@ -0,0 +1,107 @@
+//! Day 3: Rucksack Reorganization
+
+use std::collections::HashSet;
+
+use anyhow::Context;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// Solver for both parts of day 3.
+#[derive(Clone, Debug)]
+pub struct Day03;
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Vec<u8>> {
+    input
+        .lines()
+        .map(
+            |l| match l.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                Some((i, c)) => {
+                    let item = &l[i..i + c.len_utf8()];
+                    Err(ParseError::at(
+                        input,
+                        item,
+                        "item types must be ASCII letters",
+                    ))
+                }
+                None => Ok(l.as_bytes().to_vec()),
+            },
+        )
+        .collect::<Result<_, ParseError>>()?
+}
+
+/// Item types found in every one of `sacks`.
+fn common_items<'a>(sacks: impl IntoIterator<Item = &'a [u8]>) -> HashSet<u8> {
+    sacks
+        .into_iter()
+        .map(|sack| sack.iter().copied().collect::<HashSet<_>>())
+        .reduce(|a, b| a.intersection(&b).copied().collect())
+        .unwrap_or_default()
+}
+
+/// Priority of an item type, `a-z` are 1-26 and `A-Z` are 27-52.
+pub fn priority(item: u8) -> usize {
+    match item {
+        b'a'..=b'z' => (item - b'a') as usize + 1,
+        b'A'..=b'Z' => (item - b'A') as usize + 27,
+        _ => unreachable!(),
+    }
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<Vec<u8>>;
+    type Answer1 = anyhow::Result<usize>;
+    type Answer2 = anyhow::Result<usize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, sacks: &Self::Input) -> anyhow::Result<usize> {
+        sacks
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                anyhow::ensure!(
+                    s.len().is_multiple_of(2),
+                    "Compartments of rucksack {} hold different numbers of items",
+                    i + 1
+                );
+                let (first, second) = s.split_at(s.len() / 2);
+
+                let common = common_items([first, second])
+                    .into_iter()
+                    .next()
+                    .with_context(|| {
+                        format!("No item type is in both compartments of rucksack {}", i + 1)
+                    })?;
+
+                Ok(priority(common))
+            })
+            .sum()
+    }
+
+    fn part2(&self, sacks: &Self::Input) -> anyhow::Result<usize> {
+        anyhow::ensure!(
+            sacks.len().is_multiple_of(3),
+            "Rucksacks do not split into groups of three"
+        );
+
+        sacks
+            .chunks(3)
+            .enumerate()
+            .map(|(i, group)| {
+                let badge = common_items(group.iter().map(Vec::as_slice))
+                    .into_iter()
+                    .next()
+                    .with_context(|| format!("Group {} has no badge", i + 1))?;
+
+                Ok(priority(badge))
+            })
+            .sum()
+    }
+}
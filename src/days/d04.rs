@@ -0,0 +1,66 @@
+//! Day 4: Camp Cleanup
+
+use scan_fmt::scan_fmt;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::Solution;
+
+/// Solver for both parts of day 4.
+#[derive(Clone, Debug)]
+pub struct Day04;
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<(Range, Range)> {
+    input
+        .lines()
+        .map(|l| {
+            let (a, b, c, d) = scan_fmt!(l, "{d}-{d},{d}-{d}", _, _, _, _)
+                .ok()
+                .or_parse_error(input, l, "expected `a-b,c-d`")?;
+
+            Ok(((a, b), (c, d)))
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+/// Inclusive range of section IDs.
+pub type Range = (u32, u32);
+
+/// Whether the first range fully contains the second.
+pub fn contains((a, b): Range, (c, d): Range) -> bool {
+    let r = a..=b;
+    r.contains(&c) && r.contains(&d)
+}
+
+/// Whether the first range contains either end of the second.
+pub fn overlaps((a, b): Range, (c, d): Range) -> bool {
+    let r = a..=b;
+    r.contains(&c) || r.contains(&d)
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<(Range, Range)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .filter(|&&(u, v)| contains(u, v) || contains(v, u))
+            .count()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .filter(|&&(u, v)| overlaps(u, v) || overlaps(v, u))
+            .count()
+    }
+}
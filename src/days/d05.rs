@@ -0,0 +1,155 @@
+//! Day 5: Supply Stacks
+
+use scan_fmt::scan_fmt;
+
+use crate::parse::{self, ParseContext, ParseError};
+use crate::solution::Solution;
+
+/// Solver for both parts of day 5.
+#[derive(Clone, Debug)]
+pub struct Day05;
+
+/// Parses the crate drawing into stacks, bottom crate first.
+///
+/// Rows may end early where the stacks to the right are shorter, as when trailing spaces were
+/// trimmed.
+pub fn parse_stacks(raw: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let numbers = raw
+        .lines()
+        .last()
+        .ok_or_else(|| ParseError::at_end(raw, "missing stack drawing"))?;
+
+    let mut n = 0;
+    for label in numbers.split_whitespace() {
+        let number: usize = label
+            .parse()
+            .or_parse_error(raw, label, "invalid stack number")?;
+
+        if number != n + 1 {
+            let reason = format!("expected stack number {}", n + 1);
+            return Err(ParseError::at(raw, label, reason));
+        }
+        n = number;
+    }
+
+    if n == 0 {
+        return Err(ParseError::at(raw, numbers, "missing stack numbers"));
+    }
+
+    let stacks_rev = raw.lines().rev().skip(1);
+    let mut result = vec![vec![]; n];
+
+    for line in stacks_rev {
+        let bytes = line.as_bytes();
+        let indices = itertools::iterate(1, |&i| i + 4).take(n);
+
+        for (stack_no, i) in indices.enumerate() {
+            match bytes.get(i) {
+                Some(b' ') | None => (),
+                Some(&b) => result[stack_no].push(b.into()),
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<Move>) {
+    let mut blocks = parse::paragraphs(input);
+    let stacks = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "missing stack drawing"))?;
+    let moves = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "missing blank line before the moves"))?;
+
+    if let Some(extra) = blocks.next() {
+        anyhow::bail!(ParseError::at(
+            input,
+            extra,
+            "expected a single list of moves"
+        ));
+    }
+
+    let stacks = parse_stacks(stacks).map_err(|e| e.within(input, stacks))?;
+    // crates on each stack as the moves go
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+
+    let moves = moves
+        .lines()
+        .map(|l| {
+            let (n, from, to) = scan_fmt!(l, "move {d} from {d} to {d}", _, _, _)
+                .ok()
+                .or_parse_error(input, l, "expected `move N from A to B`")?;
+
+            if ![from, to]
+                .iter()
+                .all(|stack| (1..=stacks.len()).contains(stack))
+            {
+                return Err(ParseError::at(input, l, "no such stack"));
+            }
+
+            if n > heights[from - 1] {
+                let reason = format!("stack {from} only holds {} crates", heights[from - 1]);
+                return Err(ParseError::at(input, l, reason));
+            }
+            heights[from - 1] -= n;
+            heights[to - 1] += n;
+
+            Ok((from, to, n))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    (stacks, moves)
+}
+
+/// `(from, to, n)`, stacks are numbered from 1.
+pub type Move = (usize, usize, usize);
+
+/// Applies all moves and reads the top crate of every stack, skipping empty ones.
+///
+/// With `reverse` set, moved crates land in reverse order, as if moved one at a time.
+pub fn rearrange(stacks: &[Vec<char>], moves: &[Move], reverse: bool) -> String {
+    let mut stacks = stacks.to_vec();
+
+    for &(from, to, n) in moves {
+        let from = &mut stacks[from - 1];
+        let split_index = from.len() - n;
+
+        let mut transfered = from.split_off(split_index);
+
+        if reverse {
+            transfered.reverse();
+        }
+
+        let to = &mut stacks[to - 1];
+
+        to.extend(transfered);
+    }
+
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = (Vec<Vec<char>>, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    // the crane moves crates one at a time
+    fn part1(&self, (stacks, moves): &Self::Input) -> String {
+        rearrange(stacks, moves, true)
+    }
+
+    // the crane moves all crates at once
+    fn part2(&self, (stacks, moves): &Self::Input) -> String {
+        rearrange(stacks, moves, false)
+    }
+}
@@ -0,0 +1,93 @@
+//! Day 6: Tuning Trouble
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use crate::parse::ParseError;
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 6.
+#[derive(Clone, Debug)]
+pub struct Day06 {
+    /// Length of a start-of-packet marker.
+    pub packet: usize,
+    /// Length of a start-of-message marker.
+    pub message: usize,
+}
+
+pub const SOP_MARKER_LEN: usize = 4;
+pub const SOM_MARKER_LEN: usize = 14;
+
+impl Day06 {
+    pub const DEFAULT: Self = Self {
+        packet: SOP_MARKER_LEN,
+        message: SOM_MARKER_LEN,
+    };
+}
+
+impl Default for Day06 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<u8> {
+    let stream = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "empty datastream"))?;
+
+    stream.as_bytes().to_vec()
+}
+
+/// Whether all bytes in the window are different.
+pub fn is_marker(slice: &[u8]) -> bool {
+    slice.iter().copied().all_unique()
+}
+
+/// Number of bytes processed before the first marker of length `len` is complete, `None` if
+/// there is no marker.
+pub fn find_marker(stream: &[u8], len: usize) -> Option<usize> {
+    let start = stream.windows(len).position(is_marker)?;
+
+    Some(start + len)
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input = Vec<u8>;
+    type Answer1 = anyhow::Result<usize>;
+    type Answer2 = anyhow::Result<usize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, stream: &Self::Input) -> anyhow::Result<usize> {
+        find_marker(stream, self.packet).context("No start-of-packet marker in the datastream")
+    }
+
+    fn part2(&self, stream: &Self::Input) -> anyhow::Result<usize> {
+        find_marker(stream, self.message).context("No start-of-message marker in the datastream")
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("packet", self.packet.to_string()),
+            ("message", self.message.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "packet" => self.packet = solution::positive_param(name, value)?,
+            "message" => self.message = solution::positive_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,249 @@
+//! Day 7: No Space Left On Device
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 7.
+#[derive(Clone, Debug)]
+pub struct Day07 {
+    /// Total size of the disk.
+    pub disk: usize,
+    /// Free space the update needs.
+    pub update: usize,
+}
+
+impl Day07 {
+    pub const DEFAULT: Self = Self {
+        disk: DISK_SIZE,
+        update: UPDATE_SIZE,
+    };
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<ShellLine> {
+    // how deep below the root the shell is, `None` before the first `cd /`
+    let mut depth: Option<usize> = None;
+
+    let result = input
+        .lines()
+        .map(|line| {
+            let split: Vec<_> = line.split_whitespace().collect();
+
+            let parsed = match split.as_slice() {
+                ["$", "cd", "/"] => ShellLine::CdRoot,
+                _ if depth.is_none() => {
+                    return Err(ParseError::at(input, line, "expected `$ cd /` first"))
+                }
+                ["$", "ls"] => ShellLine::Ls,
+                ["$", "cd", ".."] => ShellLine::CdUp,
+                ["$", "cd", name] => ShellLine::Cd(name.to_string()),
+                ["dir", name] => ShellLine::Dir(name.to_string()),
+                [size, name] => {
+                    let size = size
+                        .parse()
+                        .or_parse_error(input, size, "invalid file size")?;
+                    ShellLine::File(name.to_string(), size)
+                }
+                _ => return Err(ParseError::at(input, line, "unrecognized terminal line")),
+            };
+
+            depth = match (&parsed, depth) {
+                (ShellLine::CdRoot, _) => Some(0),
+                (ShellLine::CdUp, Some(0)) => {
+                    return Err(ParseError::at(input, line, "already at the root"))
+                }
+                (ShellLine::CdUp, depth) => depth.map(|d| d - 1),
+                (ShellLine::Cd(_), depth) => depth.map(|d| d + 1),
+                (_, depth) => depth,
+            };
+
+            Ok(parsed)
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    if result.is_empty() {
+        anyhow::bail!(ParseError::at_end(input, "expected a terminal transcript"));
+    }
+
+    result
+}
+
+pub const SMALL_DIR_SIZE: usize = 100_000;
+pub const DISK_SIZE: usize = 70_000_000;
+pub const UPDATE_SIZE: usize = 30_000_000;
+
+/// Directory contents keyed by absolute path.
+pub type Filesystem = HashMap<String, Vec<Entry>>;
+/// Total size of each directory keyed by absolute path, including subdirectories.
+pub type DiskUsage = HashMap<String, usize>;
+
+/// A single line of the terminal transcript.
+#[derive(Debug)]
+pub enum ShellLine {
+    Cd(String),
+    CdRoot,
+    CdUp,
+    Ls,
+    Dir(String),
+    File(String, usize),
+}
+
+/// A directory entry, as listed by `ls`.
+#[derive(Debug)]
+pub enum Entry {
+    Dir(String),
+    File(String, usize),
+}
+
+/// Joins path components, the first of which must be the root `/`.
+pub fn realpath(chunks: &[&str]) -> String {
+    match chunks {
+        ["/"] => "/".to_string(),
+        ["/", rest @ ..] => itertools::chain!(&[""], rest).join("/"),
+        _ => unreachable!(),
+    }
+}
+
+/// Replays the transcript, collecting the listing of every directory visited.
+pub fn traverse(terminal: &[ShellLine]) -> Filesystem {
+    let mut cwd = vec![];
+    let mut result = Filesystem::new();
+
+    for line in terminal {
+        match line {
+            ShellLine::CdRoot => cwd = vec!["/"],
+            ShellLine::CdUp => {
+                cwd.pop();
+            }
+            ShellLine::Cd(name) => cwd.push(name),
+            ShellLine::Dir(name) => {
+                let current_dir = realpath(&cwd);
+                let d = result.entry(current_dir).or_default();
+
+                d.push(Entry::Dir(name.clone()));
+            }
+            ShellLine::File(name, size) => {
+                let current_dir = realpath(&cwd);
+                let d = result.entry(current_dir).or_default();
+
+                d.push(Entry::File(name.clone(), *size))
+            }
+            ShellLine::Ls => (),
+        }
+    }
+
+    result
+}
+
+/// Computes the total size of every directory. Directories never listed count as empty.
+pub fn disk_usage(fs: &Filesystem) -> DiskUsage {
+    let mut searchspace = vec![vec!["/"]];
+    let mut result = DiskUsage::from([("/".to_string(), 0)]);
+
+    while let Some(path) = searchspace.pop() {
+        let dirname = realpath(&path);
+
+        for entry in fs.get(&dirname).into_iter().flatten() {
+            match entry {
+                Entry::File(_, size) => {
+                    for n in 1..=path.len() {
+                        let d = realpath(&path[..n]);
+                        let entry = result.entry(d).or_default();
+
+                        *entry += size;
+                    }
+                }
+                Entry::Dir(name) => {
+                    let next = path.iter().copied().chain([name.as_str()]).collect();
+                    searchspace.push(next);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input = Vec<ShellLine>;
+    type Answer1 = usize;
+    type Answer2 = anyhow::Result<usize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, lines: &Self::Input) -> usize {
+        let fs = traverse(lines);
+        let du = disk_usage(&fs);
+
+        du.values()
+            .filter(|&&size| size <= SMALL_DIR_SIZE)
+            .sum::<usize>()
+    }
+
+    fn part2(&self, lines: &Self::Input) -> anyhow::Result<usize> {
+        let fs = traverse(lines);
+        let du = disk_usage(&fs);
+
+        let used = du["/"];
+        anyhow::ensure!(
+            used <= self.disk,
+            "Files take up {used}, more than the disk size {}",
+            self.disk
+        );
+        anyhow::ensure!(
+            self.update <= self.disk,
+            "Update of {} does not fit on a disk of {}",
+            self.update,
+            self.disk
+        );
+
+        let unused = self.disk - used;
+        let to_free = self.update.saturating_sub(unused);
+
+        if to_free == 0 {
+            return Ok(0);
+        }
+
+        // deleting everything makes room, as the update fits on the disk
+        let smallest = du
+            .values()
+            .filter(|&&size| size >= to_free)
+            .min()
+            .copied()
+            .unwrap();
+
+        Ok(smallest)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("disk", self.disk.to_string()),
+            ("update", self.update.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "disk" => self.disk = solution::parse_param(name, value)?,
+            "update" => self.update = solution::parse_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
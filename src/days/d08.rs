@@ -0,0 +1,169 @@
+//! Day 8: Treetop Tree House
+
+use itertools::Itertools;
+
+use crate::grid::{Direction, Grid, Position, DIRECTIONS4};
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// Solver for both parts of day 8.
+#[derive(Clone, Debug)]
+pub struct Day08;
+
+/// Day 8 with scenic scores computed from monotonic stacks instead of a ray per tree.
+#[derive(Clone, Debug)]
+pub struct Day08Stacks;
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Grid<u32> {
+    let grid = Grid::parse(input, |c| c.to_digit(10))?;
+
+    if grid.width() == 0 {
+        anyhow::bail!(ParseError::at_end(input, "expected a map of tree heights"));
+    }
+
+    grid
+}
+
+fn mark_visible(grid: &Grid<u32>, result: &mut Grid<bool>, line: impl Iterator<Item = Position>) {
+    let mut max = None;
+
+    for pos in line {
+        let v = grid[pos];
+
+        if max.is_none_or(|max| v > max) {
+            result[pos] = true;
+            max = Some(v);
+        }
+    }
+}
+
+/// Marks trees visible from outside the grid.
+pub fn traverse(grid: &Grid<u32>) -> Grid<bool> {
+    let width = grid.width();
+    let height = grid.height();
+    let mut result = Grid::filled(width, height, false);
+
+    let rows = (0..height).map(|y| (0..width).map(|x| (x, y)).collect_vec());
+    let columns = (0..width).map(|x| (0..height).map(|y| (x, y)).collect_vec());
+
+    for line in rows.chain(columns) {
+        mark_visible(grid, &mut result, line.iter().copied());
+        mark_visible(grid, &mut result, line.iter().rev().copied());
+    }
+
+    result
+}
+
+/// Number of trees seen from `pos` looking in direction `dir`.
+pub fn viewing_distance(grid: &Grid<u32>, pos: Position, dir: Direction) -> usize {
+    let my_tallness = grid[pos];
+    let mut dist = 0;
+
+    for (_, &v) in grid.ray(pos, dir) {
+        dist += 1;
+
+        if v >= my_tallness {
+            break;
+        }
+    }
+
+    dist
+}
+
+/// Product of viewing distances in all four directions, zero on the edge.
+pub fn scenic_score(grid: &Grid<u32>, pos: Position) -> usize {
+    DIRECTIONS4
+        .into_iter()
+        .map(|dir| viewing_distance(grid, pos, dir))
+        .product()
+}
+
+/// Multiplies the viewing distance towards the start of `line` into `scores`.
+///
+/// Trees that can still block the view of a later tree are kept on a stack, tallest at the
+/// bottom, so every tree is pushed and popped once.
+fn look_back(grid: &Grid<u32>, scores: &mut Grid<usize>, line: impl Iterator<Item = Position>) {
+    let mut blocking: Vec<(usize, u32)> = Vec::new();
+
+    for (i, pos) in line.enumerate() {
+        let v = grid[pos];
+
+        while blocking.last().is_some_and(|&(_, h)| h < v) {
+            blocking.pop();
+        }
+
+        scores[pos] *= blocking.last().map_or(i, |&(j, _)| i - j);
+        blocking.push((i, v));
+    }
+}
+
+/// Scenic score of every tree, same as [`scenic_score`] for each position.
+pub fn scenic_scores(grid: &Grid<u32>) -> Grid<usize> {
+    let width = grid.width();
+    let height = grid.height();
+    let mut result = Grid::filled(width, height, 1);
+
+    let rows = (0..height).map(|y| (0..width).map(|x| (x, y)).collect_vec());
+    let columns = (0..width).map(|x| (0..height).map(|y| (x, y)).collect_vec());
+
+    for line in rows.chain(columns) {
+        look_back(grid, &mut result, line.iter().copied());
+        look_back(grid, &mut result, line.iter().rev().copied());
+    }
+
+    result
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> usize {
+        traverse(grid)
+            .iter()
+            .filter(|(_, &visible)| visible)
+            .count()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> usize {
+        grid.positions()
+            .map(|pos| scenic_score(grid, pos))
+            .max()
+            .unwrap()
+    }
+}
+
+impl Solution for Day08Stacks {
+    const DAY: u8 = Day08::DAY;
+    const TITLE: &'static str = Day08::TITLE;
+    const VARIANT: &'static str = "stacks";
+
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Day08.parse(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> usize {
+        Day08.part1(grid)
+    }
+
+    fn part2(&self, grid: &Self::Input) -> usize {
+        scenic_scores(grid)
+            .iter()
+            .map(|(_, &score)| score)
+            .max()
+            .unwrap()
+    }
+}
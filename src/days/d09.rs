@@ -0,0 +1,166 @@
+//! Day 9: Rope Bridge
+
+use std::collections::HashSet;
+
+use scan_fmt::scan_fmt;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 9.
+#[derive(Clone, Debug)]
+pub struct Day09 {
+    /// Number of knots of the rope in part 2.
+    pub knots: usize,
+}
+
+impl Day09 {
+    pub const DEFAULT: Self = Self {
+        knots: LONG_ROPE_LEN,
+    };
+}
+
+impl Default for Day09 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Move> {
+    input
+        .lines()
+        .map(|line| {
+            let (d, n) = scan_fmt!(line, "{} {d}", _, _).ok().or_parse_error(
+                input,
+                line,
+                "expected `<direction> <steps>`",
+            )?;
+
+            let parsed = match d {
+                'L' => Move::Left(n),
+                'R' => Move::Right(n),
+                'U' => Move::Up(n),
+                'D' => Move::Down(n),
+                _ => return Err(ParseError::at(input, line, "unknown direction")),
+            };
+
+            Ok(parsed)
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+pub const SHORT_ROPE_LEN: usize = 2;
+pub const LONG_ROPE_LEN: usize = 10;
+
+/// Knot position, `y` grows upwards.
+pub type Position = (i64, i64);
+
+/// A head motion and its number of steps.
+#[derive(Clone, Copy)]
+pub enum Move {
+    Left(usize),
+    Right(usize),
+    Up(usize),
+    Down(usize),
+}
+
+impl Move {
+    /// Number of steps.
+    pub fn n(&self) -> usize {
+        match *self {
+            Move::Left(n) | Move::Right(n) | Move::Up(n) | Move::Down(n) => n,
+        }
+    }
+
+    /// Unit step vector.
+    pub fn dir(&self) -> (i64, i64) {
+        match self {
+            Move::Left(_) => (-1, 0),
+            Move::Right(_) => (1, 0),
+            Move::Up(_) => (0, 1),
+            Move::Down(_) => (0, -1),
+        }
+    }
+}
+
+/// Step the tail takes to keep up with the head.
+pub fn tail_dir((xh, yh): Position, (xt, yt): Position) -> (i64, i64) {
+    match (xh - xt, yh - yt) {
+        (-1..=1, -1..=1) => (0, 0),
+        (xd, yd) => (xd.signum(), yd.signum()),
+    }
+}
+
+/// Applies a motion to the rope, recording every position of the last knot.
+pub fn single_move(rope: &mut [Position], visited: &mut HashSet<Position>, m: Move) {
+    let n = m.n();
+    let (dir_x, dir_y) = m.dir();
+    let len = rope.len();
+
+    for _ in 0..n {
+        // move head
+        let (head_x, head_y) = &mut rope[0];
+        *head_x += dir_x;
+        *head_y += dir_y;
+
+        // move tail
+        for i in 0..(len - 1) {
+            let (xh, yh) = rope[i];
+            let (xt, yt) = &mut rope[i + 1];
+
+            let (tail_step_x, tail_step_y) = tail_dir((xh, yh), (*xt, *yt));
+            *xt += tail_step_x;
+            *yt += tail_step_y;
+        }
+
+        let true_tail = rope[len - 1];
+        visited.insert(true_tail);
+    }
+}
+
+/// Positions visited by the tail of a rope with `rope_len` knots.
+pub fn simulate(moves: &[Move], rope_len: usize) -> HashSet<Position> {
+    let mut result = [(0, 0)].into();
+    let mut rope = vec![(0, 0); rope_len];
+
+    for &m in moves {
+        single_move(&mut rope, &mut result, m);
+    }
+
+    result
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, moves: &Self::Input) -> usize {
+        simulate(moves, SHORT_ROPE_LEN).len()
+    }
+
+    fn part2(&self, moves: &Self::Input) -> usize {
+        simulate(moves, self.knots).len()
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("knots", self.knots.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "knots" => self.knots = solution::positive_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+//! Day 10: Cathode-Ray Tube
+
+use itertools::Itertools;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::Solution;
+
+/// Solver for both parts of day 10.
+#[derive(Clone, Debug)]
+pub struct Day10;
+
+/// Parses the operand of an `addx`, which has to stay within [`MAX_OPERAND`] either way.
+fn operand(input: &str, text: &str) -> Result<i64, ParseError> {
+    let v = text
+        .parse()
+        .or_parse_error(input, text, "invalid addx operand")?;
+
+    if !(-MAX_OPERAND..=MAX_OPERAND).contains(&v) {
+        let reason = format!("addx operand must be between -{MAX_OPERAND} and {MAX_OPERAND}");
+        return Err(ParseError::at(input, text, reason));
+    }
+
+    Ok(v)
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Instruction> {
+    if input.is_empty() {
+        anyhow::bail!(ParseError::at_end(input, "expected a program"));
+    }
+
+    input
+        .lines()
+        .map(|line| {
+            let split: Vec<_> = line.split_whitespace().collect();
+
+            let parsed = match split.as_slice() {
+                ["noop"] => Instruction::Noop,
+                ["addx", v] => Instruction::Addx(operand(input, v)?),
+                _ => return Err(ParseError::at(input, line, "unknown instruction")),
+            };
+
+            Ok(parsed)
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+pub const COLS: usize = 40;
+pub const ROWS: usize = 6;
+pub const LAST_SIGNAL_CYCLE: i64 = 220;
+/// Largest change of the X register by a single `addx`. Keeps the signal strength in range
+/// over the cycles the parts run.
+pub const MAX_OPERAND: i64 = 1_000_000;
+
+/// A single CPU instruction.
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+struct Fetcher {
+    instr: Instruction,
+    state: usize,
+}
+
+/// Cycle-accurate CPU running a program.
+pub struct Cpu<'a> {
+    x: i64,
+    cycle: i64,
+    pc: usize,
+    fetcher: Fetcher,
+    program: &'a [Instruction],
+}
+
+/// The screen drawn by the CPU, one pixel per cycle.
+pub struct Crt {
+    pixels: [bool; COLS * ROWS],
+}
+
+impl Fetcher {
+    fn needs_fetching(&self) -> bool {
+        matches!(
+            (self.instr, self.state),
+            (Instruction::Noop, 1) | (Instruction::Addx(_), 2)
+        )
+    }
+
+    fn increment(&mut self) {
+        self.state += 1;
+    }
+
+    fn fetch(&mut self, i: Instruction) {
+        self.instr = i;
+        self.state = 0;
+    }
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        let fetcher = Fetcher {
+            instr: program[0],
+            state: 0,
+        };
+
+        Self {
+            x: 1,
+            cycle: 1,
+            pc: 0,
+            fetcher,
+            program,
+        }
+    }
+
+    /// Value of the X register during the current cycle.
+    pub fn x(&self) -> i64 {
+        self.x
+    }
+
+    /// Current cycle, counted from 1.
+    pub fn cycle(&self) -> i64 {
+        self.cycle
+    }
+
+    /// Finishes the current cycle.
+    pub fn run_cycle(&mut self) {
+        self.fetcher.increment();
+
+        self.execute();
+
+        // past the end of the program the CPU idles
+        if self.fetcher.needs_fetching() {
+            self.pc += 1;
+            let next = self.program.get(self.pc).copied();
+            self.fetcher.fetch(next.unwrap_or(Instruction::Noop));
+        }
+
+        self.cycle += 1;
+    }
+
+    fn execute(&mut self) {
+        if let (Instruction::Addx(v), 2) = (self.fetcher.instr, self.fetcher.state) {
+            self.x += v;
+        }
+    }
+}
+
+impl Crt {
+    pub fn new() -> Self {
+        Self {
+            pixels: [false; COLS * ROWS],
+        }
+    }
+
+    /// Draws the pixel for `cycle` if the sprite at `x` covers it.
+    pub fn draw(&mut self, cycle: i64, x: i64) {
+        let pos_drawn = cycle - 1;
+        let normalized = pos_drawn % COLS as i64;
+        let sprite = (x - 1)..=(x + 1);
+
+        if sprite.contains(&normalized) {
+            let pos_drawn = pos_drawn as usize;
+            self.pixels[pos_drawn] = true;
+        }
+    }
+
+    /// Renders lit pixels as `#`.
+    pub fn print(&self) -> String {
+        self.pixels
+            .chunks(COLS)
+            .map(|row| {
+                row.iter()
+                    .map(|&p| if p { '#' } else { ' ' })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, program: &Self::Input) -> i64 {
+        let mut cpu = Cpu::new(program);
+        let mut result = 0;
+
+        loop {
+            let x = cpu.x();
+            let cycle = cpu.cycle();
+
+            if cycle % 40 == 20 {
+                result += x * cycle;
+            }
+
+            if cycle == LAST_SIGNAL_CYCLE {
+                break;
+            }
+
+            cpu.run_cycle();
+        }
+
+        result
+    }
+
+    fn part2(&self, program: &Self::Input) -> String {
+        let mut cpu = Cpu::new(program);
+        let mut crt = Crt::new();
+        let n_cycles = COLS * ROWS;
+
+        loop {
+            let x = cpu.x();
+            let cycle = cpu.cycle();
+
+            crt.draw(cycle, x);
+
+            if cycle == n_cycles as i64 {
+                break;
+            }
+
+            cpu.run_cycle();
+        }
+
+        crt.print()
+    }
+}
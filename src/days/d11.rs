@@ -0,0 +1,279 @@
+//! Day 11: Monkey in the Middle
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use crate::parse::{self, ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 11.
+#[derive(Clone, Debug)]
+pub struct Day11 {
+    /// Rounds played in part 1.
+    pub rounds1: usize,
+    /// Rounds played in part 2.
+    pub rounds2: usize,
+}
+
+impl Day11 {
+    pub const DEFAULT: Self = Self {
+        rounds1: SHORT_N_ROUNDS,
+        rounds2: LONG_N_ROUNDS,
+    };
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Parses an `Operation:` line.
+pub fn parse_operation(line: &str) -> Result<Operation, ParseError> {
+    let expr = line.strip_prefix("  Operation: new = ").or_parse_error(
+        line,
+        line,
+        "expected `Operation: new = ...`",
+    )?;
+
+    if expr == "old * old" {
+        return Ok(Operation::Square);
+    }
+
+    if let Some(v) = expr.strip_prefix("old * ") {
+        let v = v.parse().or_parse_error(line, v, "invalid factor")?;
+        return Ok(Operation::Mul(v));
+    }
+
+    if let Some(v) = expr.strip_prefix("old + ") {
+        let v = v.parse().or_parse_error(line, v, "invalid addend")?;
+        return Ok(Operation::Add(v));
+    }
+
+    Err(ParseError::at(line, expr, "unsupported operation"))
+}
+
+/// Parses the number following `prefix` on one of the lines of `desc`.
+fn parse_field<T>(desc: &str, line: &str, prefix: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let reason = format!("expected `{}<number>`", prefix.trim_start());
+
+    line.strip_prefix(prefix)
+        .or_parse_error(desc, line, &reason)?
+        .parse()
+        .or_parse_error(desc, line, &reason)
+}
+
+/// Parses a single monkey description.
+pub fn parse_monkey(desc: &str) -> Result<Monkey, ParseError> {
+    let (_, items, op, test_line, if_true, if_false) = desc
+        .lines()
+        .collect_tuple()
+        .or_parse_error(desc, desc, "expected six lines describing a monkey")?;
+
+    let items = items
+        .strip_prefix("  Starting items: ")
+        .or_parse_error(desc, items, "expected `Starting items: ...`")?
+        .split(", ")
+        .map(|s| s.parse().or_parse_error(desc, s, "invalid worry level"))
+        .collect::<Result<_, _>>()?;
+
+    let op = parse_operation(op).map_err(|e| e.within(desc, op))?;
+
+    let test = parse_field(desc, test_line, "  Test: divisible by ")?;
+    if test == 0 {
+        return Err(ParseError::at(
+            desc,
+            test_line,
+            "cannot test divisibility by zero",
+        ));
+    }
+    let if_true = parse_field(desc, if_true, "    If true: throw to monkey ")?;
+    let if_false = parse_field(desc, if_false, "    If false: throw to monkey ")?;
+
+    Ok(Monkey {
+        items,
+        op,
+        test,
+        if_true,
+        if_false,
+    })
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Monkey> {
+    let descs = parse::paragraphs(input).collect_vec();
+
+    if descs.is_empty() {
+        anyhow::bail!(ParseError::at_end(input, "expected monkey notes"));
+    }
+
+    descs
+        .iter()
+        .map(|desc| {
+            let monkey = parse_monkey(desc).map_err(|e| e.within(input, desc))?;
+
+            for (target, line) in [(monkey.if_true, 4), (monkey.if_false, 5)] {
+                if target >= descs.len() {
+                    let line = desc.lines().nth(line).unwrap_or(desc);
+                    return Err(ParseError::at(input, line, "no such monkey"));
+                }
+            }
+
+            Ok(monkey)
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+pub const SHORT_N_ROUNDS: usize = 20;
+pub const LONG_N_ROUNDS: usize = 10_000;
+pub const BOREDOM_FACTOR: u64 = 3;
+
+/// How a monkey changes the worry level of an inspected item.
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+/// A monkey and the items it currently holds.
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub op: Operation,
+    pub test: u64,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+/// Applies `op` to a worry level, `None` if it overflows.
+pub fn do_operation(worry: u64, op: Operation) -> Option<u64> {
+    match op {
+        Operation::Add(v) => worry.checked_add(v),
+        Operation::Mul(v) => worry.checked_mul(v),
+        Operation::Square => worry.checked_mul(worry),
+    }
+}
+
+/// Plays a round, returning how many items each monkey inspected, `None` if a worry level
+/// overflows.
+///
+/// `relief` is applied to every worry level after inspection.
+pub fn play_single_round(
+    monkeys: &mut [Monkey],
+    relief: impl Fn(u64) -> u64,
+) -> Option<Vec<usize>> {
+    let mut result = vec![0; monkeys.len()];
+
+    for i in 0..monkeys.len() {
+        let current = &mut monkeys[i];
+        let to_inspect = current.items.split_off(0);
+        let Monkey {
+            op,
+            test,
+            if_true,
+            if_false,
+            ..
+        } = *current;
+
+        result[i] += to_inspect.len();
+
+        for item in to_inspect {
+            let inspected = do_operation(item, op)?;
+            let relieved = relief(inspected);
+
+            let test = relieved.is_multiple_of(test);
+
+            let target = if test {
+                &mut monkeys[if_true]
+            } else {
+                &mut monkeys[if_false]
+            };
+
+            target.items.push(relieved);
+        }
+    }
+
+    Some(result)
+}
+
+/// Product of the two highest inspection counts after `n_rounds`.
+pub fn monkey_business(
+    monkeys: &[Monkey],
+    n_rounds: usize,
+    relief: impl Fn(u64) -> u64,
+) -> anyhow::Result<usize> {
+    let mut monkeys = monkeys.to_vec();
+    let mut business = vec![0; monkeys.len()];
+
+    for _ in 0..n_rounds {
+        let inspected = play_single_round(&mut monkeys, &relief)
+            .context("Worry levels grow too large to keep track of")?;
+
+        for (total, n) in business.iter_mut().zip(inspected) {
+            *total += n;
+        }
+    }
+
+    business.sort_unstable();
+
+    let [.., a, b] = business[..] else {
+        anyhow::bail!("Fewer than two monkeys to measure the business of");
+    };
+
+    a.checked_mul(b)
+        .context("Monkey business too large to keep track of")
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Input = Vec<Monkey>;
+    type Answer1 = anyhow::Result<usize>;
+    type Answer2 = anyhow::Result<usize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> anyhow::Result<usize> {
+        monkey_business(monkeys, self.rounds1, |worry| worry / BOREDOM_FACTOR)
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> anyhow::Result<usize> {
+        // chinese remainder theorem,
+        // given that the test values are pairwise coprime, worry levels are uniquely representable
+        // within the `modulo` space of the product of all test values
+        let worry_limit = monkeys
+            .iter()
+            .try_fold(1, |product: u64, m| product.checked_mul(m.test))
+            .context("Product of the test values too large to keep track of")?;
+
+        monkey_business(monkeys, self.rounds2, |worry| worry % worry_limit)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("rounds1", self.rounds1.to_string()),
+            ("rounds2", self.rounds2.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "rounds1" => self.rounds1 = solution::positive_param(name, value)?,
+            "rounds2" => self.rounds2 = solution::positive_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
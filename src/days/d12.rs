@@ -0,0 +1,147 @@
+//! Day 12: Hill Climbing Algorithm
+
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::Context;
+
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// Solver for both parts of day 12.
+#[derive(Clone, Debug)]
+pub struct Day12;
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Grid<u8> {
+    Grid::parse(input, |c| match c {
+        'S' => Some(START_MARKER),
+        'E' => Some(END_MARKER),
+        'a'..='z' => Some(c as u8 - b'a'),
+        _ => None,
+    })?
+}
+
+pub const START_MARKER: u8 = b'S';
+pub const END_MARKER: u8 = b'E';
+
+/// Locates the start and end markers, replacing them with their elevations.
+pub fn find_start_end(grid: &mut Grid<u8>) -> Option<(Position, Position)> {
+    let mut maybe_start = None;
+    let mut maybe_end = None;
+
+    for pos in grid.positions() {
+        let v = &mut grid[pos];
+
+        match *v {
+            START_MARKER => {
+                maybe_start = Some(pos);
+                *v = 0;
+            }
+            END_MARKER => {
+                maybe_end = Some(pos);
+                *v = b'z' - b'a';
+            }
+            _ => (),
+        }
+
+        if let (Some(start), Some(end)) = (maybe_start, maybe_end) {
+            return Some((start, end));
+        }
+    }
+
+    None
+}
+
+/// Length of the shortest climb from `start` to `end`.
+pub fn traverse(grid: &Grid<u8>, start: Position, end: Position) -> Option<usize> {
+    let mut searchspace: VecDeque<_> = [(start, 0)].into();
+    let mut visited = HashSet::new();
+
+    while let Some((current, len)) = searchspace.pop_front() {
+        let current_elev = grid[current];
+
+        if !visited.insert(current) {
+            continue;
+        }
+
+        for next in grid.neighbors4(current) {
+            if visited.contains(&next) {
+                continue;
+            }
+
+            let next_elev = grid[next];
+
+            if next_elev > current_elev + 1 {
+                continue;
+            }
+
+            if next == end {
+                return Some(len + 1);
+            }
+
+            searchspace.push_back((next, len + 1));
+        }
+    }
+
+    None
+}
+
+/// Length of the shortest climb to `end` from any square at the lowest elevation.
+pub fn traverse_from_end(grid: &Grid<u8>, end: Position) -> Option<usize> {
+    let mut searchspace: VecDeque<_> = [(end, 0)].into();
+    let mut visited = HashSet::new();
+
+    while let Some((current, len)) = searchspace.pop_front() {
+        let current_elev = grid[current];
+
+        if !visited.insert(current) {
+            continue;
+        }
+
+        for next in grid.neighbors4(current) {
+            if visited.contains(&next) {
+                continue;
+            }
+
+            let next_elev = grid[next];
+
+            if next_elev + 1 < current_elev {
+                continue;
+            }
+
+            if next_elev == 0 {
+                return Some(len + 1);
+            }
+
+            searchspace.push_back((next, len + 1));
+        }
+    }
+
+    None
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    type Input = (Grid<u8>, Position, Position);
+    type Answer1 = anyhow::Result<usize>;
+    type Answer2 = anyhow::Result<usize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        let mut grid = parse_input(input)?;
+        let (start, end) = find_start_end(&mut grid)
+            .ok_or_else(|| ParseError::at_end(input, "missing start (`S`) or end (`E`) marker"))?;
+
+        Ok((grid, start, end))
+    }
+
+    fn part1(&self, (grid, start, end): &Self::Input) -> anyhow::Result<usize> {
+        traverse(grid, *start, *end).context("No way up from the start to the end")
+    }
+
+    fn part2(&self, (grid, _, end): &Self::Input) -> anyhow::Result<usize> {
+        traverse_from_end(grid, *end).context("No way up from the lowest squares to the end")
+    }
+}
@@ -0,0 +1,183 @@
+//! Day 13: Distress Signal
+
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+
+use crate::parse::{self, ParseContext, ParseError};
+use crate::solution::Solution;
+
+/// Solver for both parts of day 13.
+#[derive(Clone, Debug)]
+pub struct Day13;
+
+/// Parses a single packet line.
+pub fn parse_packet(line: &str) -> Result<Entry, ParseError> {
+    // lists still open, innermost last
+    let mut stack: Vec<Vec<Entry>> = vec![];
+    let mut int_buffer: Option<u16> = None;
+    let mut packet = None;
+    let mut previous = None;
+
+    for (i, c) in line.char_indices() {
+        let text = &line[i..i + c.len_utf8()];
+
+        if packet.is_some() {
+            return Err(ParseError::at(
+                line,
+                text,
+                "unexpected text after the packet",
+            ));
+        }
+        if stack.is_empty() && c != '[' {
+            return Err(ParseError::at(line, text, "packet must be a list"));
+        }
+
+        match c {
+            '[' | '0'..='9' if previous == Some(']') => {
+                return Err(ParseError::at(line, text, "expected `,` or `]`"));
+            }
+            '[' if int_buffer.is_some() => {
+                return Err(ParseError::at(line, text, "expected `,` or `]`"));
+            }
+            '[' => stack.push(vec![]),
+            ']' | ',' => {
+                let items = stack
+                    .last_mut()
+                    .or_parse_error(line, text, "unbalanced brackets")?;
+
+                match int_buffer.take() {
+                    Some(v) => items.push(Entry::Int(v)),
+                    // the list that just closed is already in place
+                    None if previous == Some(']') => (),
+                    None if c == ']' && previous == Some('[') => (),
+                    None => return Err(ParseError::at(line, text, "empty element")),
+                }
+
+                if c == ']' {
+                    let items = Entry::List(stack.pop().unwrap_or_default());
+
+                    match stack.last_mut() {
+                        Some(outer) => outer.push(items),
+                        None => packet = Some(items),
+                    }
+                }
+            }
+            '0'..='9' => {
+                let d = c as u16 - b'0' as u16;
+                let v = int_buffer.unwrap_or_default();
+
+                let v = v
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(d))
+                    .or_parse_error(line, text, "integer too large")?;
+                int_buffer = Some(v);
+            }
+            _ => return Err(ParseError::at(line, text, "unexpected character")),
+        }
+
+        previous = Some(c);
+    }
+
+    if !stack.is_empty() {
+        return Err(ParseError::at_end(line, "unclosed list"));
+    }
+
+    packet.ok_or_else(|| ParseError::at_end(line, "packet must be a list"))
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<(Entry, Entry)> {
+    parse::paragraphs(input)
+        .map(|pair| {
+            let (a, b) = pair.lines().map(str::trim).collect_tuple().or_parse_error(
+                input,
+                pair,
+                "expected a pair of packets",
+            )?;
+
+            let a = parse_packet(a).map_err(|e| e.within(input, a))?;
+            let b = parse_packet(b).map_err(|e| e.within(input, b))?;
+
+            Ok((a, b))
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+/// A packet value: either an integer or a list of values.
+#[derive(Clone, Debug)]
+pub enum Entry {
+    List(Vec<Entry>),
+    Int(u16),
+}
+
+/// Orders packets, mixed integer/list pairs compare the integer as a one-element list.
+pub fn compare(left: &Entry, right: &Entry) -> Ordering {
+    match (left, right) {
+        (Entry::List(vl), Entry::List(vr)) => {
+            for (it_l, it_r) in std::iter::zip(vl, vr) {
+                let order = compare(it_l, it_r);
+                if !order.is_eq() {
+                    return order;
+                }
+            }
+
+            Ord::cmp(&vl.len(), &vr.len())
+        }
+        (Entry::Int(il), Entry::Int(ir)) => Ord::cmp(&il, &ir),
+        (Entry::List(vl), Entry::Int(ir)) => {
+            let list = Entry::List(vl.clone());
+            let tmp_r = Entry::List(vec![Entry::Int(*ir)]);
+
+            compare(&list, &tmp_r)
+        }
+        (Entry::Int(il), Entry::List(vr)) => {
+            let tmp_l = Entry::List(vec![Entry::Int(*il)]);
+            let list = Entry::List(vr.clone());
+
+            compare(&tmp_l, &list)
+        }
+    }
+}
+
+/// Divider packet `[[value]]`.
+pub fn divider(value: u16) -> Entry {
+    Entry::List(vec![Entry::List(vec![Entry::Int(value)])])
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+
+    type Input = Vec<(Entry, Entry)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (l, r))| compare(l, r).is_le().then_some(i + 1))
+            .sum::<usize>()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> usize {
+        let divider_a = divider(2);
+        let divider_b = divider(6);
+
+        let packets = pairs.iter().flat_map(|(l, r)| [l, r]);
+
+        itertools::chain!(
+            packets.map(|packet| (false, packet)),
+            [(true, &divider_a), (true, &divider_b)]
+        )
+        .sorted_by(|(_, a), (_, b)| compare(a, b))
+        .enumerate()
+        .filter_map(|(i, (is_divider, _))| is_divider.then_some(i + 1))
+        .product::<usize>()
+    }
+}
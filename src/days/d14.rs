@@ -0,0 +1,386 @@
+//! Day 14: Regolith Reservoir
+
+use itertools::Itertools;
+
+use crate::grid::{Direction, Grid, Position};
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 14.
+#[derive(Clone, Debug)]
+pub struct Day14 {
+    /// Where the sand is poured from.
+    pub source: Position,
+}
+
+impl Day14 {
+    pub const DEFAULT: Self = Self { source: START_POS };
+
+    /// The cave with all rock paths drawn, the source and the depth past which sand falls into
+    /// the abyss, or the depth it rests at on the floor if there is one.
+    ///
+    /// Sand spreads sideways at most one column per row it falls, so the cave reaches that far
+    /// to both sides of the source, wherever it is. Columns are shifted to start at the leftmost
+    /// one sand or rock can reach.
+    fn cave(&self, paths: &[Path], floor: bool) -> (Grid<Space>, Position, usize) {
+        let (source_x, source_y) = self.source;
+        let (rocks_max_x, rocks_max_y) = max_coords(paths);
+        let rocks_min_x = paths
+            .iter()
+            .flatten()
+            .map(|&(x, _)| x)
+            .min()
+            .unwrap_or(source_x);
+
+        let depth = rocks_max_y.max(source_y) + usize::from(floor);
+        // columns are counted from `reach` left of where they are, so none goes below zero
+        let reach = depth + 1;
+        let left = (rocks_min_x + reach).min(source_x);
+        let right = (rocks_max_x + reach).max(source_x + 2 * reach);
+
+        let shifted = paths
+            .iter()
+            .map(|path| path.iter().map(|&(x, y)| (x + reach - left, y)).collect())
+            .collect_vec();
+        let cave = place_rocks(&shifted, right - left, depth + 1);
+
+        (cave, (source_x + reach - left, source_y), depth)
+    }
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Day 14 with each unit of sand starting from where the previous one last moved.
+#[derive(Clone, Debug, Default)]
+pub struct Day14Memo(pub Day14);
+
+impl Day14Memo {
+    pub const DEFAULT: Self = Self(Day14::DEFAULT);
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Path> {
+    if input.is_empty() {
+        anyhow::bail!(ParseError::at_end(input, "expected rock paths"));
+    }
+
+    input
+        .lines()
+        .map(|line| {
+            let split = line.split(" -> ");
+
+            let path = split
+                .map(|s| {
+                    let (x, y) = s
+                        .split_once(',')
+                        .or_parse_error(input, s, "expected `x,y`")?;
+                    let x = coordinate(input, x)?;
+                    let y = coordinate(input, y)?;
+
+                    Ok((x, y))
+                })
+                .collect::<Result<Path, ParseError>>()?;
+
+            let diagonal = path
+                .iter()
+                .tuple_windows()
+                .any(|((s_x, s_y), (e_x, e_y))| s_x != e_x && s_y != e_y);
+            if diagonal {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "rock paths must be straight lines",
+                ));
+            }
+
+            Ok(path)
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+/// Parses a coordinate of a rock path corner, which has to lie within the scanned cave.
+fn coordinate(input: &str, text: &str) -> Result<usize, ParseError> {
+    let n = text
+        .parse()
+        .or_parse_error(input, text, "invalid coordinate")?;
+
+    if n > MAX_COORD {
+        let reason = format!("coordinates must be at most {MAX_COORD}");
+        return Err(ParseError::at(input, text, reason));
+    }
+
+    Ok(n)
+}
+
+/// Largest coordinate of a rock or of the source. The cave grows with their square.
+pub const MAX_COORD: usize = 999;
+
+/// Where the sand is poured from, unless told otherwise.
+pub const START_POS: Position = (500, 0);
+
+/// Where sand tries to move, in order of preference.
+pub const FALL_DIRECTIONS: [Direction; 3] = [(0, 1), (-1, 1), (1, 1)];
+
+/// Corners of a rock structure, connected with straight lines.
+pub type Path = Vec<Position>;
+
+/// Contents of a single cave tile.
+#[derive(Clone, Copy)]
+pub enum Space {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Space {
+    pub fn is_air(&self) -> bool {
+        matches!(self, Self::Air)
+    }
+}
+
+/// Largest `x` and `y` over all path corners.
+pub fn max_coords(paths: &[Path]) -> (usize, usize) {
+    paths
+        .iter()
+        .flatten()
+        .fold((0, 0), |(max_x, max_y), &(x, y)| {
+            let new_x = std::cmp::max(max_x, x);
+            let new_y = std::cmp::max(max_y, y);
+
+            (new_x, new_y)
+        })
+}
+
+/// All positions on a horizontal or vertical line, both ends included.
+pub fn line((s_x, s_y): Position, (e_x, e_y): Position) -> Vec<Position> {
+    match ((s_x, s_y), (e_x, e_y)) {
+        ((_, start), (_, end)) if s_x == e_x => {
+            let l_start = std::cmp::min(start, end);
+            let l_end = std::cmp::max(start, end);
+
+            (l_start..=l_end).map(|y| (s_x, y)).collect()
+        }
+        ((start, _), (end, _)) if s_y == e_y => {
+            let l_start = std::cmp::min(start, end);
+            let l_end = std::cmp::max(start, end);
+
+            (l_start..=l_end).map(|x| (x, s_y)).collect()
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Builds a cave big enough to fit `(max_x, max_y)` with all rock paths drawn.
+pub fn place_rocks(paths: &[Path], max_x: usize, max_y: usize) -> Grid<Space> {
+    let width = max_x + 1;
+    let height = max_y + 1;
+    let mut grid = Grid::filled(width, height, Space::Air);
+
+    for path in paths {
+        for (start, end) in path.iter().copied().tuple_windows() {
+            for pos in line(start, end) {
+                grid[pos] = Space::Rock;
+            }
+        }
+    }
+
+    grid
+}
+
+/// First free position sand at `sand` can fall to, if any.
+pub fn fall_target(cave: &Grid<Space>, sand: Position) -> Option<Position> {
+    FALL_DIRECTIONS.into_iter().find_map(|dir| {
+        let target = cave
+            .step(sand, dir)
+            .expect("sand fell off the side of the cave");
+
+        cave[target].is_air().then_some(target)
+    })
+}
+
+/// Pours a unit of sand from `source`, returning whether it fell past `max_y`.
+pub fn pour_into_abyss(cave: &mut Grid<Space>, source: Position, max_y: usize) -> bool {
+    let mut sand = source;
+
+    loop {
+        let maybe_target = fall_target(cave, sand);
+
+        match maybe_target {
+            Some((_, y)) if y >= max_y => {
+                return true;
+            }
+            Some(target) => {
+                sand = target;
+            }
+            None => {
+                cave[sand] = Space::Sand;
+                return false;
+            }
+        }
+    }
+}
+
+/// Pours a unit of sand from `source` onto a floor below `max_y`, returning where it came to
+/// rest.
+pub fn pour_onto_floor(cave: &mut Grid<Space>, source: Position, max_y: usize) -> Position {
+    let mut sand = source;
+
+    loop {
+        let maybe_target = fall_target(cave, sand);
+
+        match maybe_target {
+            Some(target @ (_, y)) => {
+                sand = target;
+
+                if y >= max_y {
+                    cave[sand] = Space::Sand;
+                    return sand;
+                }
+            }
+            None => {
+                cave[sand] = Space::Sand;
+                return sand;
+            }
+        }
+    }
+}
+
+/// Pours sand from `source` until it flows past `max_y`, or onto a floor below `max_y` until
+/// the source is blocked, returning how many units came to rest.
+///
+/// Every unit follows the path of the previous one up to the tile before it came to rest, so
+/// the path is kept and each unit starts falling from its last position.
+pub fn pour_along_path(
+    cave: &mut Grid<Space>,
+    source: Position,
+    max_y: usize,
+    floor: bool,
+) -> usize {
+    let mut path = vec![source];
+    let mut rested = 0;
+
+    while let Some(&sand) = path.last() {
+        match fall_target(cave, sand) {
+            Some((_, y)) if y >= max_y && !floor => {
+                break;
+            }
+            Some(target @ (_, y)) if y >= max_y => {
+                cave[target] = Space::Sand;
+                rested += 1;
+            }
+            Some(target) => {
+                path.push(target);
+            }
+            None => {
+                cave[sand] = Space::Sand;
+                rested += 1;
+                path.pop();
+            }
+        }
+    }
+
+    rested
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+
+    type Input = Vec<Path>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, paths: &Self::Input) -> usize {
+        let (mut cave, source, max_y) = self.cave(paths, false);
+        let mut rested = 0;
+
+        // wide enough rocks catch all the sand until it piles up to the source
+        while cave[source].is_air() && !pour_into_abyss(&mut cave, source, max_y) {
+            rested += 1;
+        }
+
+        rested
+    }
+
+    fn part2(&self, paths: &Self::Input) -> usize {
+        let (mut cave, source, max_y) = self.cave(paths, true);
+
+        let poured = std::iter::repeat_with(|| pour_onto_floor(&mut cave, source, max_y))
+            .take_while(|&rest_pos| rest_pos != source)
+            .count();
+
+        poured + 1
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let (x, y) = self.source;
+
+        vec![("source", format!("{x},{y}"))]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "source" => {
+                let (x, y) = value
+                    .split_once(',')
+                    .ok_or_else(|| anyhow::anyhow!("Parameter `{name}` has to look like `x,y`"))?;
+
+                let source = (
+                    solution::parse_param(name, x)?,
+                    solution::parse_param(name, y)?,
+                );
+                anyhow::ensure!(
+                    source.0 <= MAX_COORD && source.1 <= MAX_COORD,
+                    "Parameter `{name}` has to be at most {MAX_COORD},{MAX_COORD}"
+                );
+
+                self.source = source;
+            }
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
+
+impl Solution for Day14Memo {
+    const DAY: u8 = Day14::DAY;
+    const TITLE: &'static str = Day14::TITLE;
+    const VARIANT: &'static str = "memo";
+
+    type Input = Vec<Path>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        self.0.parse(input)
+    }
+
+    fn part1(&self, paths: &Self::Input) -> usize {
+        let (mut cave, source, max_y) = self.0.cave(paths, false);
+
+        pour_along_path(&mut cave, source, max_y, false)
+    }
+
+    fn part2(&self, paths: &Self::Input) -> usize {
+        let (mut cave, source, max_y) = self.0.cave(paths, true);
+
+        pour_along_path(&mut cave, source, max_y, true)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.params()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        self.0.set_param(name, value)
+    }
+}
@@ -0,0 +1,281 @@
+//! Day 15: Beacon Exclusion Zone
+
+use std::collections::HashSet;
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 15.
+#[derive(Clone, Debug)]
+pub struct Day15 {
+    /// Row scanned in part 1.
+    pub row: isize,
+    /// Largest coordinate the distress beacon can have.
+    pub max: isize,
+}
+
+impl Day15 {
+    pub const DEFAULT: Self = Self {
+        row: SCANNED_ROW,
+        max: MAX_COORD,
+    };
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Day 15 with the scanned row counted from merged intervals instead of a set of columns.
+#[derive(Clone, Debug, Default)]
+pub struct Day15Intervals(pub Day15);
+
+impl Day15Intervals {
+    pub const DEFAULT: Self = Self(Day15::DEFAULT);
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<(Position, Position)> {
+    if input.is_empty() {
+        anyhow::bail!(ParseError::at_end(input, "expected sensor reports"));
+    }
+
+    input
+        .lines()
+        .map(|line| {
+            let [sx, sy, bx, by] =
+                report_numbers(line).or_parse_error(input, line, "expected a sensor report")?;
+
+            Ok((
+                (coordinate(input, sx)?, coordinate(input, sy)?),
+                (coordinate(input, bx)?, coordinate(input, by)?),
+            ))
+        })
+        .collect::<Result<_, ParseError>>()?
+}
+
+/// The coordinates of the sensor and the beacon in a report line, unparsed.
+fn report_numbers(line: &str) -> Option<[&str; 4]> {
+    let rest = line.strip_prefix("Sensor at x=")?;
+    let (sx, rest) = rest.split_once(", y=")?;
+    let (sy, rest) = rest.split_once(": closest beacon is at x=")?;
+    let (bx, by) = rest.split_once(", y=")?;
+
+    Some([sx, sy, bx, by])
+}
+
+/// Parses a coordinate of a sensor or beacon, which has to lie within [`MAX_REACH`] of the
+/// origin.
+fn coordinate(input: &str, text: &str) -> Result<isize, ParseError> {
+    let n = text
+        .parse()
+        .or_parse_error(input, text, "invalid coordinate")?;
+
+    if !(-MAX_REACH..=MAX_REACH).contains(&n) {
+        let reason = format!("coordinates must be between -{MAX_REACH} and {MAX_REACH}");
+        return Err(ParseError::at(input, text, reason));
+    }
+
+    Ok(n)
+}
+
+/// Largest coordinate of a sensor or beacon either way. Keeps distances, the scanned row and the
+/// tuning frequency in range.
+pub const MAX_REACH: isize = 10_000_000;
+pub const SCANNED_ROW: isize = 2_000_000;
+pub const MIN_COORD: isize = 0;
+pub const MAX_COORD: isize = 4_000_000;
+pub const TUNING_FACTOR: isize = 4_000_000;
+
+pub type Position = (isize, isize);
+/// Columns covered by at least one sensor.
+pub type Scan = HashSet<isize>;
+
+pub fn manhattan((x, y): Position, (t_x, t_y): Position) -> isize {
+    isize::abs_diff(x, t_x) as isize + isize::abs_diff(y, t_y) as isize
+}
+
+/// Columns in `row` where no undetected beacon can be.
+pub fn scan_row(report: &[(Position, Position)], row: isize) -> Scan {
+    let mut result = Scan::new();
+
+    for &(sensor @ (sx, sy), beacon) in report {
+        let radius = manhattan(sensor, beacon);
+        let dist_from_row = isize::abs_diff(sy, row) as isize;
+
+        if dist_from_row > radius {
+            continue;
+        }
+
+        let span = radius - dist_from_row;
+        let sweeped = (-span..=span).map(|dx| sx + dx);
+
+        result.extend(sweeped)
+    }
+
+    result
+}
+
+/// Columns in `row` where no undetected beacon can be, as sorted disjoint inclusive intervals.
+pub fn row_intervals(report: &[(Position, Position)], row: isize) -> Vec<(isize, isize)> {
+    report
+        .iter()
+        .filter_map(|&(sensor @ (sx, sy), beacon)| {
+            let span = manhattan(sensor, beacon) - isize::abs_diff(sy, row) as isize;
+
+            (span >= 0).then_some((sx - span, sx + span))
+        })
+        .sorted()
+        .coalesce(|(a, b), (c, d)| {
+            if c <= b + 1 {
+                Ok((a, std::cmp::max(b, d)))
+            } else {
+                Err(((a, b), (c, d)))
+            }
+        })
+        .collect()
+}
+
+/// Finds the only position with coordinates up to `max` not covered by any sensor, `None` if they
+/// cover every position.
+pub fn search(report: &[(Position, Position)], max: isize) -> Option<Position> {
+    let sensors = report
+        .iter()
+        .map(|&(sensor, beacon)| (sensor, manhattan(sensor, beacon)))
+        .collect_vec();
+
+    for y in MIN_COORD..=max {
+        let spans_x = sensors
+            .iter()
+            .filter_map(|&((sx, sy), radius)| {
+                let vert = isize::abs_diff(y, sy) as isize;
+
+                if vert > radius {
+                    return None;
+                }
+
+                let span = radius - vert;
+                let lower = std::cmp::max(MIN_COORD, sx - span);
+                let upper = std::cmp::min(max, sx + span);
+
+                Some((lower, upper))
+            })
+            .sorted()
+            .coalesce(|(a, b), (c, d)| {
+                if d <= b {
+                    Ok((a, b))
+                } else if c <= b {
+                    Ok((a, d))
+                } else {
+                    Err(((a, b), (c, d)))
+                }
+            });
+
+        if let Some(((_, upper), _)) = spans_x.collect_tuple() {
+            return Some((upper + 1, y));
+        }
+    }
+
+    None
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    type Input = Vec<(Position, Position)>;
+    type Answer1 = usize;
+    type Answer2 = anyhow::Result<isize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, report: &Self::Input) -> usize {
+        let excluded_beacons = report
+            .iter()
+            .copied()
+            .map(|(_, beacon)| beacon)
+            .filter(|&(_, y)| y == self.row)
+            .collect_vec();
+        let scanned = scan_row(report, self.row);
+
+        scanned
+            .iter()
+            .filter(|&&x| !excluded_beacons.contains(&(x, self.row)))
+            .count()
+    }
+
+    fn part2(&self, report: &Self::Input) -> anyhow::Result<isize> {
+        let (x, y) = search(report, self.max).context("Could not find distress beacon")?;
+
+        Ok(x * TUNING_FACTOR + y)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("row", self.row.to_string()), ("max", self.max.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        let field = match name {
+            "row" => &mut self.row,
+            "max" => &mut self.max,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        };
+
+        let value = solution::parse_param(name, value)?;
+        anyhow::ensure!(
+            (-MAX_REACH..=MAX_REACH).contains(&value),
+            "Parameter `{name}` has to be between -{MAX_REACH} and {MAX_REACH}"
+        );
+        *field = value;
+
+        Ok(())
+    }
+}
+
+impl Solution for Day15Intervals {
+    const DAY: u8 = Day15::DAY;
+    const TITLE: &'static str = Day15::TITLE;
+    const VARIANT: &'static str = "intervals";
+
+    type Input = Vec<(Position, Position)>;
+    type Answer1 = usize;
+    type Answer2 = anyhow::Result<isize>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        self.0.parse(input)
+    }
+
+    // every beacon on the row lies within the interval of the sensor that found it
+    fn part1(&self, report: &Self::Input) -> usize {
+        let scanned: isize = row_intervals(report, self.0.row)
+            .iter()
+            .map(|(lower, upper)| upper - lower + 1)
+            .sum();
+        let beacons = report
+            .iter()
+            .map(|&(_, beacon)| beacon)
+            .filter(|&(_, y)| y == self.0.row)
+            .unique()
+            .count();
+
+        scanned as usize - beacons
+    }
+
+    fn part2(&self, report: &Self::Input) -> anyhow::Result<isize> {
+        self.0.part2(report)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.params()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        self.0.set_param(name, value)
+    }
+}
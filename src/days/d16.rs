@@ -0,0 +1,313 @@
+//! Day 16: Proboscidea Volcanium
+
+use std::collections::{HashMap, VecDeque};
+
+use itertools::Itertools;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 16.
+#[derive(Clone, Debug)]
+pub struct Day16 {
+    /// Minutes until the eruption when opening valves alone.
+    pub minutes1: u32,
+    /// Minutes left after teaching the elephant.
+    pub minutes2: u32,
+}
+
+impl Day16 {
+    pub const DEFAULT: Self = Self {
+        minutes1: TIME_ALONE,
+        minutes2: TIME_WITH_ELEPHANT,
+    };
+}
+
+impl Default for Day16 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+pub const TIME_ALONE: u32 = 30;
+pub const TIME_WITH_ELEPHANT: u32 = 26;
+/// Valve everyone starts at.
+pub const START_VALVE: &str = "AA";
+
+/// A valve as described by a line of the scan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Valve<'a> {
+    pub name: &'a str,
+    pub rate: u16,
+    pub tunnels: Vec<&'a str>,
+}
+
+/// Parses a `Valve AA has flow rate=0; tunnels lead to valves DD, II, BB` line.
+pub fn parse_valve(line: &str) -> Result<Valve<'_>, ParseError> {
+    const EXPECTED: &str = "expected `Valve XX has flow rate=N; tunnels lead to valves ...`";
+
+    let (name, rest) = line
+        .strip_prefix("Valve ")
+        .and_then(|rest| rest.split_once(" has flow rate="))
+        .or_parse_error(line, line, EXPECTED)?;
+    let (rate, tunnels) = rest.split_once("; ").or_parse_error(line, rest, EXPECTED)?;
+
+    let rate = rate
+        .parse()
+        .or_parse_error(line, rate, "invalid flow rate")?;
+    let tunnels = tunnels
+        .strip_prefix("tunnels lead to valves ")
+        .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+        .or_parse_error(line, tunnels, "expected `tunnels lead to valves ...`")?
+        .split(", ")
+        .collect_vec();
+
+    if name.is_empty() || name.contains(' ') {
+        return Err(ParseError::at(line, name, "invalid valve name"));
+    }
+
+    Ok(Valve {
+        name,
+        rate,
+        tunnels,
+    })
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Network {
+    let valves = input
+        .lines()
+        .map(|line| parse_valve(line).map_err(|e| e.within(input, line)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut indices = HashMap::new();
+    for (i, valve) in valves.iter().enumerate() {
+        if indices.insert(valve.name, i).is_some() {
+            anyhow::bail!(ParseError::at(input, valve.name, "valve described twice"));
+        }
+    }
+
+    let tunnels = valves
+        .iter()
+        .map(|valve| {
+            valve
+                .tunnels
+                .iter()
+                .map(|&name| {
+                    indices
+                        .get(name)
+                        .copied()
+                        .or_parse_error(input, name, "no such valve")
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let start = indices
+        .get(START_VALVE)
+        .copied()
+        .ok_or_else(|| ParseError::at_end(input, format!("missing valve {START_VALVE}")))?;
+
+    let rates = valves.iter().map(|valve| valve.rate.into()).collect_vec();
+    let network = compress(&rates, &tunnels, start);
+
+    if network.rates.len() > MAX_VALVES {
+        let reason = format!("more than {MAX_VALVES} valves with a flow rate");
+        anyhow::bail!(ParseError::at_end(input, reason));
+    }
+
+    network
+}
+
+/// Most valves with a flow rate that fit into the set of open valves.
+pub const MAX_VALVES: usize = 64;
+
+/// The valves worth opening, with travel times between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Network {
+    /// Flow rates of the valves worth opening.
+    pub rates: Vec<u64>,
+    /// Minutes from one valve to another, `None` if unreachable. The start valve comes after
+    /// the valves in `rates`, whether it has a flow rate or not.
+    pub dist: Vec<Vec<Option<u32>>>,
+}
+
+impl Network {
+    /// Index of the start valve in `dist`.
+    pub fn start(&self) -> usize {
+        self.rates.len()
+    }
+}
+
+/// Minutes from `from` to every valve through `tunnels`, `None` for unreachable ones.
+pub fn distances(tunnels: &[Vec<usize>], from: usize) -> Vec<Option<u32>> {
+    let mut result = vec![None; tunnels.len()];
+    let mut queue: VecDeque<_> = [(from, 0)].into();
+    result[from] = Some(0);
+
+    while let Some((valve, dist)) = queue.pop_front() {
+        for &next in &tunnels[valve] {
+            if result[next].is_none() {
+                result[next] = Some(dist + 1);
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Keeps only the valves with a flow rate and the `start` valve, connecting every pair of them
+/// directly.
+pub fn compress(rates: &[u64], tunnels: &[Vec<usize>], start: usize) -> Network {
+    let mut kept = (0..rates.len()).filter(|&i| rates[i] > 0).collect_vec();
+    kept.push(start);
+
+    let dist = kept
+        .iter()
+        .map(|&from| {
+            let all = distances(tunnels, from);
+            kept.iter().map(|&to| all[to]).collect()
+        })
+        .collect();
+
+    Network {
+        rates: kept[..kept.len() - 1].iter().map(|&i| rates[i]).collect(),
+        dist,
+    }
+}
+
+/// Most pressure released in `minutes` for every set of opened valves, as a bit mask over
+/// [`Network::rates`].
+pub fn explore(network: &Network, minutes: u32) -> HashMap<u64, u64> {
+    let mut best = HashMap::new();
+    let mut seen = HashMap::new();
+    visit(
+        network,
+        network.start(),
+        minutes,
+        0,
+        0,
+        &mut best,
+        &mut seen,
+    );
+
+    best
+}
+
+/// Most pressure released on reaching a valve with a set of valves opened and minutes left.
+type Seen = HashMap<(usize, u64, u32), u64>;
+
+fn visit(
+    network: &Network,
+    at: usize,
+    minutes: u32,
+    opened: u64,
+    released: u64,
+    best: &mut HashMap<u64, u64>,
+    seen: &mut Seen,
+) {
+    // opening the same valves in another order got here with at least as much, so whatever
+    // follows was already tried
+    match seen.get(&(at, opened, minutes)) {
+        Some(&before) if before >= released => return,
+        _ => seen.insert((at, opened, minutes), released),
+    };
+
+    let entry = best.entry(opened).or_insert(0);
+    *entry = std::cmp::max(*entry, released);
+
+    for (next, &rate) in network.rates.iter().enumerate() {
+        if opened & (1 << next) != 0 {
+            continue;
+        }
+
+        // walking there and opening it has to leave some time for the flow
+        let Some(cost) = network.dist[at][next].map(|dist| dist + 1) else {
+            continue;
+        };
+        if cost >= minutes {
+            continue;
+        }
+
+        let left = minutes - cost;
+        visit(
+            network,
+            next,
+            left,
+            opened | 1 << next,
+            released + u64::from(left) * rate,
+            best,
+            seen,
+        );
+    }
+}
+
+/// Most pressure two actors opening separate valves release in `minutes`.
+pub fn explore_in_pairs(network: &Network, minutes: u32) -> u64 {
+    let best = explore(network, minutes)
+        .into_iter()
+        .sorted_by_key(|&(_, released)| std::cmp::Reverse(released))
+        .collect_vec();
+    let mut result = 0;
+
+    for (i, &(mine, released)) in best.iter().enumerate() {
+        // the rest only gets worse
+        if released * 2 < result {
+            break;
+        }
+
+        for &(theirs, other) in &best[i..] {
+            if released + other <= result {
+                break;
+            }
+
+            if mine & theirs == 0 {
+                result = released + other;
+            }
+        }
+    }
+
+    result
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Proboscidea Volcanium";
+
+    type Input = Network;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, network: &Self::Input) -> u64 {
+        explore(network, self.minutes1)
+            .into_values()
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn part2(&self, network: &Self::Input) -> u64 {
+        explore_in_pairs(network, self.minutes2)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("minutes1", self.minutes1.to_string()),
+            ("minutes2", self.minutes2.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "minutes1" => self.minutes1 = solution::parse_param(name, value)?,
+            "minutes2" => self.minutes2 = solution::parse_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
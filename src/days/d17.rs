@@ -0,0 +1,247 @@
+//! Day 17: Pyroclastic Flow
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 17.
+#[derive(Clone, Debug)]
+pub struct Day17 {
+    /// Rocks dropped in part 1.
+    pub rocks1: u64,
+    /// Rocks dropped in part 2.
+    pub rocks2: u64,
+}
+
+impl Day17 {
+    pub const DEFAULT: Self = Self {
+        rocks1: SHORT_N_ROCKS,
+        rocks2: LONG_N_ROCKS,
+    };
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Jet> {
+    let jets = input
+        .trim_end()
+        .char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => {
+                let text = &input[i..i + c.len_utf8()];
+                Err(ParseError::at(input, text, "expected `<` or `>`"))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if jets.is_empty() {
+        anyhow::bail!(ParseError::at_end(input, "expected a jet pattern"));
+    }
+
+    jets
+}
+
+pub const SHORT_N_ROCKS: u64 = 2022;
+pub const LONG_N_ROCKS: u64 = 1_000_000_000_000;
+pub const CHAMBER_WIDTH: usize = 7;
+/// How many rows of the chamber below the top are compared when looking for a repeating state.
+pub const SURFACE_DEPTH: usize = 64;
+
+/// Cells of the rocks in the order they fall, relative to their bottom left corner.
+///
+/// Unlike on the other days `y` grows upwards, as the chamber fills from the floor up.
+pub const ROCKS: [&[Position]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+/// The chamber rocks fall into, pushed around by the jets.
+#[derive(Clone, Debug)]
+pub struct Chamber<'a> {
+    jets: &'a [Jet],
+    next_jet: usize,
+    /// Settled rock, rows from the floor up as high as the tower.
+    cells: Grid<bool>,
+    dropped: u64,
+}
+
+/// Where a chamber repeats itself: the next rock, the next jet and the surface.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    rock: usize,
+    jet: usize,
+    surface: Vec<bool>,
+}
+
+impl<'a> Chamber<'a> {
+    /// Panics if there are no `jets`.
+    pub fn new(jets: &'a [Jet]) -> Self {
+        assert!(!jets.is_empty(), "no jets");
+
+        Self {
+            jets,
+            next_jet: 0,
+            cells: Grid::filled(CHAMBER_WIDTH, 0, false),
+            dropped: 0,
+        }
+    }
+
+    /// Height of the tower of settled rocks.
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// Number of rocks that came to rest so far.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Whether `rock` fits at `(x, y)` without hitting the walls, the floor or settled rock.
+    fn fits(&self, rock: &[Position], (x, y): Position) -> bool {
+        rock.iter().all(|&(dx, dy)| {
+            let (x, y) = (x + dx, y + dy);
+            // above the tower is open air, but not beside the chamber
+            x < CHAMBER_WIDTH && self.cells.get((x, y)).is_none_or(|&rock| !rock)
+        })
+    }
+
+    /// Drops the next rock until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        let rock = ROCKS[(self.dropped % ROCKS.len() as u64) as usize];
+        let mut pos: Position = (2, self.height() + 3);
+
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+
+            let pushed = match jet {
+                Jet::Left => pos.0.checked_sub(1).map(|x| (x, pos.1)),
+                Jet::Right => Some((pos.0 + 1, pos.1)),
+            };
+            if let Some(pushed) = pushed.filter(|&pushed| self.fits(rock, pushed)) {
+                pos = pushed;
+            }
+
+            match pos.1.checked_sub(1).map(|y| (pos.0, y)) {
+                Some(fallen) if self.fits(rock, fallen) => pos = fallen,
+                _ => break,
+            }
+        }
+
+        for &(dx, dy) in rock {
+            let (x, y) = (pos.0 + dx, pos.1 + dy);
+            if y >= self.height() {
+                self.cells.extend_rows(y + 1 - self.height(), false);
+            }
+            self.cells[(x, y)] = true;
+        }
+
+        self.dropped += 1;
+    }
+
+    /// The state deciding how the tower grows from here on, as far as [`SURFACE_DEPTH`] rows
+    /// below the top tell.
+    pub fn fingerprint(&self) -> Fingerprint {
+        let surface = (0..self.height())
+            .rev()
+            .take(SURFACE_DEPTH)
+            .flat_map(|y| self.cells.row(y))
+            .copied()
+            .collect();
+
+        Fingerprint {
+            rock: (self.dropped % ROCKS.len() as u64) as usize,
+            jet: self.next_jet,
+            surface,
+        }
+    }
+}
+
+/// Height of the tower after `rocks` rocks, skipping ahead once the chamber repeats itself.
+pub fn tower_height(jets: &[Jet], rocks: u64) -> u64 {
+    let mut chamber = Chamber::new(jets);
+    let mut seen = HashMap::new();
+    let mut skipped = None;
+
+    while chamber.dropped() < rocks {
+        chamber.drop_rock();
+
+        if skipped.is_some() {
+            continue;
+        }
+
+        let height = chamber.height() as u64;
+        match seen.entry(chamber.fingerprint()) {
+            Entry::Vacant(entry) => {
+                entry.insert((chamber.dropped(), height));
+            }
+            Entry::Occupied(entry) => {
+                let (dropped, prev_height) = *entry.get();
+                let period = chamber.dropped() - dropped;
+                let cycles = (rocks - chamber.dropped()) / period;
+
+                chamber.dropped += cycles * period;
+                skipped = Some(cycles * (height - prev_height));
+            }
+        }
+    }
+
+    chamber.height() as u64 + skipped.unwrap_or(0)
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Pyroclastic Flow";
+
+    type Input = Vec<Jet>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, jets: &Self::Input) -> u64 {
+        tower_height(jets, self.rocks1)
+    }
+
+    fn part2(&self, jets: &Self::Input) -> u64 {
+        tower_height(jets, self.rocks2)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("rocks1", self.rocks1.to_string()),
+            ("rocks2", self.rocks2.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "rocks1" => self.rocks1 = solution::parse_param(name, value)?,
+            "rocks2" => self.rocks2 = solution::parse_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
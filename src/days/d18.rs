@@ -0,0 +1,164 @@
+//! Day 18: Boiling Boulders
+
+use std::collections::HashSet;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::Solution;
+
+/// Solver for both parts of day 18.
+#[derive(Clone, Debug)]
+pub struct Day18;
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> HashSet<Cube> {
+    let mut result = HashSet::new();
+
+    for line in input.lines() {
+        let mut coords = line.split(',');
+        let (Some(x), Some(y), Some(z), None) =
+            (coords.next(), coords.next(), coords.next(), coords.next())
+        else {
+            anyhow::bail!(ParseError::at(input, line, "expected `x,y,z`"));
+        };
+        let cube = (
+            coordinate(input, x)?,
+            coordinate(input, y)?,
+            coordinate(input, z)?,
+        );
+
+        if !result.insert(cube) {
+            anyhow::bail!(ParseError::at(input, line, "cube scanned twice"));
+        }
+    }
+
+    result
+}
+
+/// Parses one coordinate of a scanned cube, which has to lie within the scanned space.
+fn coordinate(input: &str, text: &str) -> Result<i32, ParseError> {
+    let n = text
+        .parse()
+        .or_parse_error(input, text, "expected a number")?;
+
+    if !(0..=MAX_COORD).contains(&n) {
+        let reason = format!("coordinates must be between 0 and {MAX_COORD}");
+        return Err(ParseError::at(input, text, reason));
+    }
+
+    Ok(n)
+}
+
+/// Largest coordinate scanned. Keeps the box flooded around the droplet small.
+pub const MAX_COORD: i32 = 99;
+
+/// `(x, y, z)` position of a unit cube.
+pub type Cube = (i32, i32, i32);
+
+/// Offsets to the six cubes sharing a face with a cube.
+pub const SIDES: [Cube; 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+/// Cubes sharing a face with `cube`.
+pub fn neighbors6((x, y, z): Cube) -> impl Iterator<Item = Cube> {
+    SIDES
+        .into_iter()
+        .map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz))
+}
+
+/// Number of faces not covered by another cube.
+pub fn surface_area(cubes: &HashSet<Cube>) -> usize {
+    cubes
+        .iter()
+        .flat_map(|&cube| neighbors6(cube))
+        .filter(|next| !cubes.contains(next))
+        .count()
+}
+
+/// Number of faces reachable by water flowing around the droplet, leaving out air pockets.
+///
+/// Floods the bounding box of `cubes`, grown by one so water gets all around, from a corner.
+pub fn exterior_surface_area(cubes: &HashSet<Cube>) -> usize {
+    let Some(((min_x, min_y, min_z), (max_x, max_y, max_z))) = bounds(cubes) else {
+        return 0;
+    };
+    let (min, max) = (
+        (min_x - 1, min_y - 1, min_z - 1),
+        (max_x + 1, max_y + 1, max_z + 1),
+    );
+    let size = (max.0 - min.0 + 1, max.1 - min.1 + 1, max.2 - min.2 + 1);
+    // index of a cube in the box, `None` outside of it
+    let index = |(x, y, z): Cube| {
+        let (x, y, z) = (x - min.0, y - min.1, z - min.2);
+        let inside =
+            (0..size.0).contains(&x) && (0..size.1).contains(&y) && (0..size.2).contains(&z);
+
+        inside.then(|| ((z * size.1 + y) * size.0 + x) as usize)
+    };
+
+    let mut lava = vec![false; (size.0 * size.1 * size.2) as usize];
+    for &cube in cubes {
+        lava[index(cube).expect("cube in its bounding box")] = true;
+    }
+
+    let mut searchspace = vec![min];
+    let mut visited = vec![false; lava.len()];
+    visited[index(min).expect("corner in the box")] = true;
+    let mut result = 0;
+
+    while let Some(current) = searchspace.pop() {
+        for next in neighbors6(current) {
+            let Some(i) = index(next) else {
+                continue;
+            };
+
+            if lava[i] {
+                result += 1;
+            } else if !visited[i] {
+                visited[i] = true;
+                searchspace.push(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Smallest and largest coordinates along each axis, `None` without cubes.
+pub fn bounds(cubes: &HashSet<Cube>) -> Option<(Cube, Cube)> {
+    cubes.iter().fold(None, |bounds, &(x, y, z)| {
+        let ((min_x, min_y, min_z), (max_x, max_y, max_z)) =
+            bounds.unwrap_or(((x, y, z), (x, y, z)));
+
+        Some((
+            (min_x.min(x), min_y.min(y), min_z.min(z)),
+            (max_x.max(x), max_y.max(y), max_z.max(z)),
+        ))
+    })
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Boiling Boulders";
+
+    type Input = HashSet<Cube>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, cubes: &Self::Input) -> usize {
+        surface_area(cubes)
+    }
+
+    fn part2(&self, cubes: &Self::Input) -> usize {
+        exterior_surface_area(cubes)
+    }
+}
@@ -0,0 +1,236 @@
+//! Day 19: Not Enough Minerals
+
+use scan_fmt::scan_fmt;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 19.
+#[derive(Clone, Debug)]
+pub struct Day19 {
+    /// Minutes to collect geodes in part 1.
+    pub minutes1: u32,
+    /// Minutes to collect geodes in part 2.
+    pub minutes2: u32,
+    /// Blueprints the elephants left uneaten for part 2.
+    pub blueprints2: usize,
+}
+
+impl Day19 {
+    pub const DEFAULT: Self = Self {
+        minutes1: SHORT_TIME,
+        minutes2: LONG_TIME,
+        blueprints2: UNEATEN_BLUEPRINTS,
+    };
+}
+
+impl Default for Day19 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Parses a `Blueprint 1: Each ore robot costs 4 ore. ...` line.
+pub fn parse_blueprint(line: &str) -> Result<Blueprint, ParseError> {
+    let (id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = scan_fmt!(
+        line,
+        "Blueprint {d}: Each ore robot costs {d} ore. Each clay robot costs {d} ore. \
+         Each obsidian robot costs {d} ore and {d} clay. \
+         Each geode robot costs {d} ore and {d} obsidian.",
+        u32,
+        u16,
+        u16,
+        u16,
+        u16,
+        u16,
+        u16
+    )
+    .ok()
+    .or_parse_error(
+        line,
+        line,
+        "expected a blueprint with costs of at most 65535",
+    )?;
+
+    Ok(Blueprint {
+        id,
+        costs: [
+            [ore.into(), 0, 0],
+            [clay.into(), 0, 0],
+            [obsidian_ore.into(), obsidian_clay.into(), 0],
+            [geode_ore.into(), 0, geode_obsidian.into()],
+        ],
+    })
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<Blueprint> {
+    input
+        .lines()
+        .map(|line| parse_blueprint(line).map_err(|e| e.within(input, line)))
+        .collect::<Result<_, _>>()?
+}
+
+pub const SHORT_TIME: u32 = 24;
+pub const LONG_TIME: u32 = 32;
+pub const UNEATEN_BLUEPRINTS: usize = 3;
+
+pub const ORE: usize = 0;
+pub const CLAY: usize = 1;
+pub const OBSIDIAN: usize = 2;
+pub const GEODE: usize = 3;
+
+/// Robot costs, indexed by the robot's kind and then by resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Blueprint {
+    pub id: u32,
+    /// Ore, clay and obsidian taken by each kind of robot: [`ORE`], [`CLAY`], [`OBSIDIAN`]
+    /// and [`GEODE`]. Parsed costs fit in a `u16`.
+    pub costs: [[u32; 3]; 4],
+}
+
+impl Blueprint {
+    /// Most of each resource any robot takes. Mining more of it per minute is never needed, as
+    /// only one robot is built at a time.
+    pub fn max_needed(&self) -> [u32; 3] {
+        let mut result = [0; 3];
+
+        for cost in &self.costs {
+            for (max, &amount) in result.iter_mut().zip(cost) {
+                *max = (*max).max(amount);
+            }
+        }
+
+        result
+    }
+}
+
+/// Where the search stands: time left, stock and working robots of each mined resource, and
+/// geodes cracked by the end by the geode robots built so far.
+#[derive(Clone, Copy, Debug)]
+struct State {
+    minutes: u32,
+    stock: [u32; 3],
+    robots: [u32; 3],
+    geodes: u64,
+}
+
+/// Minutes until `stock` covers `cost`, given the `robots` mining, `None` if it never does.
+fn wait_for(cost: &[u32; 3], stock: &[u32; 3], robots: &[u32; 3]) -> Option<u32> {
+    (0..3).try_fold(0, |wait, i| {
+        let missing = cost[i].saturating_sub(stock[i]);
+
+        match (missing, robots[i]) {
+            (0, _) => Some(wait),
+            (_, 0) => None,
+            (missing, robots) => Some(wait.max(missing.div_ceil(robots))),
+        }
+    })
+}
+
+/// Most geodes cracked in `minutes` following `blueprint`.
+///
+/// Searches depth first over which robot to build next, waiting as long as it takes to afford
+/// it. Branches that cannot beat the best so far, even building a geode robot every minute left,
+/// are cut off.
+pub fn max_geodes(blueprint: &Blueprint, minutes: u32) -> u64 {
+    let max_needed = blueprint.max_needed();
+    let start = State {
+        minutes,
+        stock: [0; 3],
+        robots: [1, 0, 0],
+        geodes: 0,
+    };
+    let mut best = 0;
+
+    search(blueprint, &max_needed, start, &mut best);
+
+    best
+}
+
+fn search(blueprint: &Blueprint, max_needed: &[u32; 3], state: State, best: &mut u64) {
+    *best = (*best).max(state.geodes);
+
+    let minutes = u64::from(state.minutes);
+    if state.geodes + minutes * minutes.saturating_sub(1) / 2 <= *best {
+        return;
+    }
+
+    for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+        if robot != GEODE && state.robots[robot] >= max_needed[robot] {
+            continue;
+        }
+
+        let cost = &blueprint.costs[robot];
+        let Some(wait) = wait_for(cost, &state.stock, &state.robots) else {
+            continue;
+        };
+        // a robot finished in the last minute has no time left to mine
+        let elapsed = wait.saturating_add(1);
+        if elapsed >= state.minutes {
+            continue;
+        }
+
+        let mut next = state;
+        next.minutes -= elapsed;
+        for ((stock, robots), cost) in next.stock.iter_mut().zip(state.robots).zip(cost) {
+            *stock += robots * elapsed;
+            *stock -= cost;
+        }
+
+        if robot == GEODE {
+            next.geodes += u64::from(next.minutes);
+        } else {
+            next.robots[robot] += 1;
+        }
+
+        search(blueprint, max_needed, next, best);
+    }
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Not Enough Minerals";
+
+    type Input = Vec<Blueprint>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, blueprints: &Self::Input) -> u64 {
+        blueprints
+            .iter()
+            .map(|blueprint| u64::from(blueprint.id) * max_geodes(blueprint, self.minutes1))
+            .sum()
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> u64 {
+        blueprints
+            .iter()
+            .take(self.blueprints2)
+            .map(|blueprint| max_geodes(blueprint, self.minutes2))
+            .product()
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("minutes1", self.minutes1.to_string()),
+            ("minutes2", self.minutes2.to_string()),
+            ("blueprints2", self.blueprints2.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "minutes1" => self.minutes1 = solution::parse_param(name, value)?,
+            "minutes2" => self.minutes2 = solution::parse_param(name, value)?,
+            "blueprints2" => self.blueprints2 = solution::positive_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
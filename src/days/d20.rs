@@ -0,0 +1,282 @@
+//! Day 20: Grove Positioning System
+
+use itertools::Itertools;
+
+use crate::parse::{ParseContext, ParseError};
+use crate::solution::{self, Solution};
+
+/// Solver for both parts of day 20.
+#[derive(Clone, Debug)]
+pub struct Day20 {
+    /// Decryption key every number is multiplied by in part 2.
+    pub key: i32,
+    /// Times the list is mixed in part 2.
+    pub rounds: usize,
+}
+
+impl Day20 {
+    pub const DEFAULT: Self = Self {
+        key: DECRYPTION_KEY,
+        rounds: N_ROUNDS,
+    };
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Day 20 moving numbers around in a plain `Vec`, each move shifting everything in between.
+#[derive(Clone, Debug, Default)]
+pub struct Day20Vec(pub Day20);
+
+impl Day20Vec {
+    pub const DEFAULT: Self = Self(Day20::DEFAULT);
+}
+
+#[anyhoo::anyhoo]
+fn parse_input(input: &str) -> Vec<i16> {
+    let mut result = vec![];
+    let mut zero = None;
+
+    for line in input.lines() {
+        let n = line
+            .parse()
+            .or_parse_error(input, line, "expected a number")?;
+
+        if n == 0 && zero.replace(line).is_some() {
+            anyhow::bail!(ParseError::at(input, line, "more than one 0 in the list"));
+        }
+
+        result.push(n);
+    }
+
+    if zero.is_none() {
+        anyhow::bail!(ParseError::at_end(input, "expected a 0 in the list"));
+    }
+
+    result
+}
+
+pub const DECRYPTION_KEY: i32 = 811_589_153;
+pub const N_ROUNDS: usize = 10;
+/// Positions after the 0 holding the grove coordinates.
+pub const GROVE_OFFSETS: [usize; 3] = [1000, 2000, 3000];
+
+/// An ordering of the numbers, by their index in the original list.
+pub trait Sequence {
+    /// Takes `item` out, returning the index it was at.
+    fn take_out(&mut self, item: usize) -> usize;
+
+    /// Puts `item` back in at `index`.
+    fn put_back(&mut self, index: usize, item: usize);
+
+    /// Every item, in order.
+    fn items(&self) -> Vec<usize>;
+}
+
+impl Sequence for Vec<usize> {
+    fn take_out(&mut self, item: usize) -> usize {
+        let index = self
+            .iter()
+            .position(|&i| i == item)
+            .expect("item in the sequence");
+        self.remove(index);
+
+        index
+    }
+
+    fn put_back(&mut self, index: usize, item: usize) {
+        self.insert(index, item);
+    }
+
+    fn items(&self) -> Vec<usize> {
+        self.clone()
+    }
+}
+
+/// A sequence split into blocks of about `sqrt(n)` items, so taking out and putting back an item
+/// costs O(sqrt(n)) instead of shifting everything behind it.
+#[derive(Clone, Debug)]
+pub struct BlockList {
+    blocks: Vec<Vec<usize>>,
+    /// Index of the block holding each item.
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl BlockList {
+    /// Items `0..len` in order.
+    pub fn new(len: usize) -> Self {
+        let mut result = Self {
+            blocks: vec![],
+            block_of: vec![0; len],
+            block_size: len.isqrt().max(1),
+        };
+        result.rebuild((0..len).collect());
+
+        result
+    }
+
+    /// Splits `items` into evenly sized blocks again.
+    fn rebuild(&mut self, items: Vec<usize>) {
+        self.blocks = items
+            .chunks(self.block_size)
+            .map(<[usize]>::to_vec)
+            .collect();
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            for &item in block {
+                self.block_of[item] = i;
+            }
+        }
+    }
+}
+
+impl Sequence for BlockList {
+    fn take_out(&mut self, item: usize) -> usize {
+        let block = self.block_of[item];
+        let before: usize = self.blocks[..block].iter().map(Vec::len).sum();
+
+        before + self.blocks[block].take_out(item)
+    }
+
+    fn put_back(&mut self, index: usize, item: usize) {
+        let mut block = 0;
+        let mut index = index;
+
+        // the last block takes whatever lies past the end
+        while block + 1 < self.blocks.len() && index > self.blocks[block].len() {
+            index -= self.blocks[block].len();
+            block += 1;
+        }
+
+        self.blocks[block].insert(index, item);
+        self.block_of[item] = block;
+
+        if self.blocks[block].len() > 2 * self.block_size {
+            self.rebuild(self.items());
+        }
+    }
+
+    fn items(&self) -> Vec<usize> {
+        self.blocks.iter().flatten().copied().collect()
+    }
+}
+
+/// Moves every number, in their original order, as many places forward or back as it says.
+pub fn mix(order: &mut impl Sequence, numbers: &[i64]) {
+    // moving past every other number leads back to the same place
+    let Some(others) = (numbers.len() as i64).checked_sub(1).filter(|&n| n > 0) else {
+        return;
+    };
+
+    for (item, &n) in numbers.iter().enumerate() {
+        let from = order.take_out(item);
+        let to = (from as i64 + n).rem_euclid(others);
+        order.put_back(to as usize, item);
+    }
+}
+
+/// Sum of the numbers [`GROVE_OFFSETS`] places after the 0 in `order`.
+pub fn grove_coordinates(numbers: &[i64], order: &[usize]) -> i64 {
+    let zero = order
+        .iter()
+        .position(|&i| numbers[i] == 0)
+        .expect("a 0 in the list");
+
+    GROVE_OFFSETS
+        .iter()
+        .map(|offset| numbers[order[(zero + offset) % order.len()]])
+        .sum()
+}
+
+/// Grove coordinates after multiplying every number by `key` and mixing `rounds` times.
+pub fn decrypt(mut order: impl Sequence, numbers: &[i16], key: i32, rounds: usize) -> i64 {
+    let numbers = numbers
+        .iter()
+        .map(|&n| i64::from(n) * i64::from(key))
+        .collect_vec();
+
+    for _ in 0..rounds {
+        mix(&mut order, &numbers);
+    }
+
+    grove_coordinates(&numbers, &order.items())
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Grove Positioning System";
+
+    type Input = Vec<i16>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> i64 {
+        decrypt(BlockList::new(numbers.len()), numbers, 1, 1)
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> i64 {
+        decrypt(
+            BlockList::new(numbers.len()),
+            numbers,
+            self.key,
+            self.rounds,
+        )
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("key", self.key.to_string()),
+            ("rounds", self.rounds.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "key" => self.key = solution::parse_param(name, value)?,
+            "rounds" => self.rounds = solution::parse_param(name, value)?,
+            _ => anyhow::bail!("No parameter `{name}`"),
+        }
+
+        Ok(())
+    }
+}
+
+impl Solution for Day20Vec {
+    const DAY: u8 = Day20::DAY;
+    const TITLE: &'static str = Day20::TITLE;
+    const VARIANT: &'static str = "vec";
+
+    type Input = Vec<i16>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        self.0.parse(input)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> i64 {
+        decrypt((0..numbers.len()).collect_vec(), numbers, 1, 1)
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> i64 {
+        let order = (0..numbers.len()).collect_vec();
+
+        decrypt(order, numbers, self.0.key, self.0.rounds)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.params()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        self.0.set_param(name, value)
+    }
+}
@@ -0,0 +1,70 @@
+//! Solutions for each day, one module per day.
+
+use crate::solution::DynSolution;
+
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+pub mod d11;
+pub mod d12;
+pub mod d13;
+pub mod d14;
+pub mod d15;
+pub mod d16;
+pub mod d17;
+pub mod d18;
+pub mod d19;
+pub mod d20;
+
+/// Every solved day, in order.
+pub const ALL: &[&dyn DynSolution] = &[
+    &d01::Day01,
+    &d02::Day02,
+    &d03::Day03,
+    &d04::Day04,
+    &d05::Day05,
+    &d06::Day06::DEFAULT,
+    &d07::Day07::DEFAULT,
+    &d08::Day08,
+    &d09::Day09::DEFAULT,
+    &d10::Day10,
+    &d11::Day11::DEFAULT,
+    &d12::Day12,
+    &d13::Day13,
+    &d14::Day14::DEFAULT,
+    &d15::Day15::DEFAULT,
+    &d16::Day16::DEFAULT,
+    &d17::Day17::DEFAULT,
+    &d18::Day18,
+    &d19::Day19::DEFAULT,
+    &d20::Day20::DEFAULT,
+];
+
+/// Alternative implementations of days in [`ALL`], which have to give the same answers.
+pub const VARIANTS: &[&dyn DynSolution] = &[
+    &d08::Day08Stacks,
+    &d14::Day14Memo::DEFAULT,
+    &d15::Day15Intervals::DEFAULT,
+    &d20::Day20Vec::DEFAULT,
+];
+
+/// Looks up the solution for `day`.
+pub fn get(day: u8) -> Option<&'static dyn DynSolution> {
+    ALL.iter().copied().find(|s| s.day() == day)
+}
+
+/// Every implementation of `day`, starting with the one in [`ALL`].
+pub fn implementations(day: u8) -> Vec<&'static dyn DynSolution> {
+    ALL.iter()
+        .chain(VARIANTS)
+        .copied()
+        .filter(|s| s.day() == day)
+        .collect()
+}
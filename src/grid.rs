@@ -0,0 +1,230 @@
+//! Row-major 2D grid shared by the map-based days.
+
+use std::ops::{Index, IndexMut};
+
+use crate::parse::ParseError;
+
+/// `(x, y)` cell coordinates, `y` grows downwards.
+pub type Position = (usize, usize);
+
+/// Step vector `(dx, dy)`.
+pub type Direction = (isize, isize);
+
+/// Left, right, up, down.
+pub const DIRECTIONS4: [Direction; 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// [`DIRECTIONS4`] followed by the four diagonals.
+pub const DIRECTIONS8: [Direction; 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `cells` cannot be split into rows of `width`.
+    pub fn new(cells: Vec<T>, width: usize) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or_default();
+        assert_eq!(width * height, cells.len(), "ragged grid");
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(vec![value; width * height], width)
+    }
+
+    /// Adds `rows` rows of `value` after the last one.
+    pub fn extend_rows(&mut self, rows: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.cells
+            .resize(self.cells.len() + rows * self.width, value);
+        self.height += rows;
+    }
+
+    /// Parses a character map, one row per line, mapping every character with `f`.
+    ///
+    /// Fails on rows of different lengths and on characters `f` rejects.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+
+        for line in input.lines() {
+            let row_start = cells.len();
+
+            for (i, c) in line.char_indices() {
+                let Some(cell) = f(c) else {
+                    let text = &line[i..i + c.len_utf8()];
+                    return Err(ParseError::at(input, text, "unexpected character"));
+                };
+
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    let reason = format!("row is {row_width} cells wide, expected {w}");
+                    return Err(ParseError::at(input, line, reason));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Self::new(cells, width.unwrap_or_default()))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        let index = self.index(pos);
+        Some(&mut self.cells[index])
+    }
+
+    /// Position one step from `pos` in direction `dir`, if it stays on the grid.
+    pub fn step(&self, (x, y): Position, (dx, dy): Direction) -> Option<Position> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        self.contains((x, y)).then_some((x, y))
+    }
+
+    /// Orthogonally adjacent positions.
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS4
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Orthogonally and diagonally adjacent positions.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS8
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Cells walked from `pos` in direction `dir` up to the edge, `pos` itself excluded.
+    pub fn ray(&self, pos: Position, dir: Direction) -> impl Iterator<Item = (Position, &T)> + '_ {
+        std::iter::successors(self.step(pos, dir), move |&p| self.step(p, dir))
+            .map(|p| (p, &self[p]))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        let start = y * self.width;
+
+        &self.cells[start..start + self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {x} out of bounds");
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        itertools::iproduct!(0..self.height, 0..self.width).map(|(y, x)| (x, y))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.cells.iter().map(f).collect(), self.width)
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Self::new(cells, self.height)
+    }
+
+    /// Draws the grid one line per row, using `f` to pick each cell's character.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+
+        for y in 0..self.height {
+            if y > 0 {
+                result.push('\n');
+            }
+
+            result.extend(self.row(y).iter().map(&mut f));
+        }
+
+        result
+    }
+
+    fn index(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
@@ -0,0 +1,173 @@
+//! Benchmark results kept across runs, to catch days that got slower.
+//!
+//! The history is a JSON-lines file with one [`Entry`] per benchmarked step. Entries written by
+//! the same `--bench` invocation share a run id.
+
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Where the history is kept unless told otherwise.
+pub const DEFAULT_PATH: &str = "bench-history.jsonl";
+
+/// Median time of a single benchmarked step.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Start of the benchmark run in milliseconds since the Unix epoch.
+    pub run: u64,
+    pub commit: Option<String>,
+    /// Name given to the run with `--label`, for use as a baseline.
+    pub label: Option<String>,
+    pub day: u8,
+    /// Input the step ran on, as shown in the benchmark table.
+    #[serde(default)]
+    pub input: String,
+    /// Parameters given with `--param`, in order.
+    #[serde(default)]
+    pub params: Vec<(String, String)>,
+    /// `parse`, `part1` or `part2`.
+    pub step: String,
+    pub median_ns: u64,
+}
+
+/// All entries of a single benchmark run.
+#[derive(Clone, Debug)]
+pub struct Run<'a> {
+    pub id: u64,
+    pub commit: Option<&'a str>,
+    pub label: Option<&'a str>,
+    pub entries: Vec<&'a Entry>,
+}
+
+/// Timing of one step in two runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub day: u8,
+    pub step: String,
+    pub baseline_ns: u64,
+    pub latest_ns: u64,
+}
+
+impl Change {
+    /// Relative change of the latest time, `0.1` is 10% slower.
+    pub fn relative(&self) -> f64 {
+        self.latest_ns as f64 / self.baseline_ns.max(1) as f64 - 1.0
+    }
+
+    /// Whether the step got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.relative() * 100.0 > threshold
+    }
+}
+
+/// Reads the history at `path`, a missing file is an empty history.
+pub fn load(path: &Path) -> Result<Vec<Entry>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e).with_context(|| format!("Cannot read {}", path.display())),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("{}:{}: invalid history entry", path.display(), i + 1))
+        })
+        .collect()
+}
+
+/// Appends `entries` to the history at `path`, creating it if needed.
+pub fn append(path: &Path, entries: &[Entry]) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Cannot open {}", path.display()))?;
+
+    for entry in entries {
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    }
+
+    Ok(())
+}
+
+/// Groups entries into runs, in the order the runs were first recorded.
+pub fn runs(entries: &[Entry]) -> Vec<Run<'_>> {
+    let mut result: Vec<Run> = vec![];
+
+    for entry in entries {
+        match result.iter_mut().find(|run| run.id == entry.run) {
+            Some(run) => run.entries.push(entry),
+            None => result.push(Run {
+                id: entry.run,
+                commit: entry.commit.as_deref(),
+                label: entry.label.as_deref(),
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    result
+}
+
+/// Picks the latest run and the one to compare it against: the latest earlier run labelled
+/// `baseline`, or the previous run when no label is given.
+pub fn select<'r, 'a>(
+    runs: &'r [Run<'a>],
+    baseline: Option<&str>,
+) -> Option<(&'r Run<'a>, &'r Run<'a>)> {
+    let (latest, earlier) = runs.split_last()?;
+
+    let base = match baseline {
+        Some(label) => earlier.iter().rev().find(|run| run.label == Some(label))?,
+        None => earlier.last()?,
+    };
+
+    Some((latest, base))
+}
+
+/// Steps timed in both runs on the same input with the same parameters, in the order of
+/// `latest`.
+pub fn compare(latest: &Run, baseline: &Run) -> Vec<Change> {
+    latest
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let base = baseline.entries.iter().find(|b| {
+                b.day == entry.day
+                    && b.step == entry.step
+                    && b.input == entry.input
+                    && b.params == entry.params
+            })?;
+
+            Some(Change {
+                day: entry.day,
+                step: entry.step.clone(),
+                baseline_ns: base.median_ns,
+                latest_ns: entry.median_ns,
+            })
+        })
+        .collect()
+}
+
+/// Commit checked out in the current directory, if it is a git repository.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8(output.stdout).ok()?;
+    Some(commit.trim().to_owned())
+}
@@ -0,0 +1,168 @@
+//! Puzzle inputs: found in `input/`, in the per-user cache, or downloaded on a cache miss.
+//!
+//! Inputs in `input/` may also be [encrypted](crate::vault), they are decrypted when read.
+//!
+//! Nothing is downloaded unless a [`Client`] can be configured from the environment. The cache
+//! lives in `AOC_CACHE_DIR`, or in `$XDG_CACHE_HOME/aoc2022-rust` or `~/.cache/aoc2022-rust` when
+//! that is not set.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::client::Client;
+use crate::vault::{self, Key};
+
+/// Where the inputs checked into the working tree live.
+pub const LOCAL_DIR: &str = "input";
+
+const CACHE_DIR_NAME: &str = "aoc2022-rust";
+
+/// Source of puzzle inputs for cache misses.
+pub trait Fetch {
+    fn fetch(&self, day: u8) -> Result<String>;
+}
+
+/// Downloads inputs from the Advent of Code website.
+pub struct HttpFetcher {
+    client: Client,
+}
+
+impl HttpFetcher {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Fetcher using [`Client::from_env`], `None` without a session token.
+    pub fn from_env() -> Option<Self> {
+        Client::from_env().map(Self::new)
+    }
+}
+
+impl Fetch for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String> {
+        let url = self.client.day_url(day, "input");
+
+        let response = self
+            .client
+            .request("GET", &url)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(404, _) => anyhow::anyhow!("Day {day} is not unlocked yet"),
+                ureq::Error::Status(400 | 500, _) => {
+                    anyhow::anyhow!("Fetching {url} failed, is the session token still valid?")
+                }
+                e => anyhow::anyhow!("Fetching {url} failed: {e}"),
+            })?;
+
+        response
+            .into_string()
+            .with_context(|| format!("Cannot read the response from {url}"))
+    }
+}
+
+/// Per-user cache directory, see the [module docs](self).
+pub fn default_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+        return Some(dir.into());
+    }
+
+    let cache_home = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+
+    Some(cache_home.join(CACHE_DIR_NAME))
+}
+
+/// File name of the input of `day`, the same in every directory.
+pub fn file_name(day: u8) -> String {
+    format!("d{day:02}.txt")
+}
+
+/// Reads the input at `path`, decrypting it with the default key if it is encrypted.
+pub fn read(path: &Path) -> Result<String> {
+    if !vault::is_encrypted(path) {
+        return fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()));
+    }
+
+    let key_path = vault::default_key_path().ok_or_else(|| {
+        anyhow::anyhow!(
+            "No key file to decrypt {}, set AOC_INPUT_KEY",
+            path.display()
+        )
+    })?;
+
+    vault::read(&Key::load(&key_path)?, path)
+}
+
+/// Finds inputs in a local directory and a cache, downloading them into the cache when missing.
+pub struct InputStore {
+    local_dir: PathBuf,
+    cache_dir: Option<PathBuf>,
+    fetcher: Option<Box<dyn Fetch>>,
+}
+
+impl InputStore {
+    pub fn new(
+        local_dir: impl Into<PathBuf>,
+        cache_dir: Option<PathBuf>,
+        fetcher: Option<Box<dyn Fetch>>,
+    ) -> Self {
+        Self {
+            local_dir: local_dir.into(),
+            cache_dir,
+            fetcher,
+        }
+    }
+
+    /// Store using `input/`, the default cache directory and [`HttpFetcher::from_env`].
+    pub fn from_env() -> Self {
+        let fetcher = HttpFetcher::from_env().map(|f| Box::new(f) as Box<dyn Fetch>);
+
+        Self::new(LOCAL_DIR, default_cache_dir(), fetcher)
+    }
+
+    /// Path of an existing input for `day`, if there is one locally or in the cache.
+    ///
+    /// A plain local input is preferred over an encrypted one, to pick up edits of decrypted
+    /// inputs.
+    pub fn find(&self, day: u8) -> Option<PathBuf> {
+        let local = self.local_dir.join(file_name(day));
+        let encrypted = vault::encrypted_path(&local);
+        let cached = self.cache_dir.as_ref().map(|dir| dir.join(file_name(day)));
+
+        [Some(local), Some(encrypted), cached]
+            .into_iter()
+            .flatten()
+            .find(|path| path.is_file())
+    }
+
+    /// Path of the input for `day`, downloading it into the cache if needed.
+    ///
+    /// `None` when the input is nowhere to be found and there is no way to fetch it.
+    pub fn resolve(&self, day: u8) -> Result<Option<PathBuf>> {
+        if let Some(path) = self.find(day) {
+            return Ok(Some(path));
+        }
+
+        let (Some(fetcher), Some(cache_dir)) = (&self.fetcher, &self.cache_dir) else {
+            return Ok(None);
+        };
+
+        let input = fetcher.fetch(day)?;
+
+        fs::create_dir_all(cache_dir)
+            .with_context(|| format!("Cannot create {}", cache_dir.display()))?;
+        let path = cache_dir.join(file_name(day));
+        fs::write(&path, input).with_context(|| format!("Cannot write {}", path.display()))?;
+
+        Ok(Some(path))
+    }
+
+    /// Whether missing inputs can be downloaded.
+    pub fn can_fetch(&self) -> bool {
+        self.fetcher.is_some() && self.cache_dir.is_some()
+    }
+}
@@ -0,0 +1,35 @@
+//! Advent of Code 2022 solutions.
+//!
+//! Every day lives in its own module under [`days`] and is solved by a struct implementing
+//! [`Solution`], whose fields are the puzzle constants of the day, if it has any. The helpers
+//! each solver is built from are public as well, so they can be reused without going through
+//! the `aoc` runner:
+//!
+//! ```no_run
+//! use aoc2022_rust::days::d07::Day07;
+//! use aoc2022_rust::Solution;
+//!
+//! let input = std::fs::read_to_string("input/d07.txt")?;
+//! let day = Day07::default();
+//! let lines = day.parse(&input)?;
+//!
+//! println!("{}", day.part1(&lines));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+pub mod alloc_stats;
+pub mod bench;
+pub mod client;
+pub mod cross_check;
+pub mod days;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod parse;
+pub mod solution;
+pub mod submit;
+pub mod vault;
+pub mod watch;
+
+pub use parse::ParseError;
+pub use solution::{Answer, DynSolution, Part, Solution};
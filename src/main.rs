@@ -0,0 +1,1210 @@
+use std::any::Any;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{Command as Process, Stdio};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+use aoc2022_rust::alloc_stats::{self, AllocStats};
+use aoc2022_rust::bench::{self, BenchConfig, Stats};
+use aoc2022_rust::client::Client;
+use aoc2022_rust::cross_check;
+use aoc2022_rust::days;
+use aoc2022_rust::history::{self, Run};
+use aoc2022_rust::input::{self, InputStore};
+use aoc2022_rust::solution::{DynSolution, Part};
+use aoc2022_rust::submit::{HttpSubmitter, SubmissionLog, Verdict};
+use aoc2022_rust::vault::{self, Key};
+use aoc2022_rust::watch::{self, FileWatcher, RunResult};
+use aoc2022_rust::ParseError;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for a single day, or for every day with --all
+    Run {
+        /// Day to run
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Run only the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file, `-` reads stdin [default: input/dXX.txt]
+        input: Option<PathBuf>,
+
+        /// Change a puzzle constant of the day, e.g. `--param row=10` on day 15
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+
+        /// Run every registered solution against its default input
+        #[arg(long, conflicts_with_all = ["day", "part", "input", "params"])]
+        all: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        #[command(flatten)]
+        bench: BenchArgs,
+    },
+    /// Download puzzle inputs into the cache, printing where each one is
+    Fetch {
+        /// Day to fetch
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Fetch the input of every registered solution
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+    },
+    /// Submit an answer, computed from the day's input unless given
+    Submit {
+        /// Day of the answer
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part of the answer
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit [default: the solution's answer for the day's input]
+        answer: Option<String>,
+
+        /// File recording submitted answers [default: submissions.jsonl in the cache directory]
+        #[arg(long, value_name = "PATH")]
+        log: Option<PathBuf>,
+    },
+    /// Encrypt inputs in input/, creating the key file if there is none
+    Encrypt {
+        /// Day to encrypt
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Encrypt every plain input in input/
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+
+        /// Keep the plain inputs instead of removing them
+        #[arg(long)]
+        keep: bool,
+    },
+    /// Decrypt encrypted inputs in input/ next to them
+    Decrypt {
+        /// Day to decrypt
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Decrypt every encrypted input in input/
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+    },
+    /// Re-encrypt every encrypted input in input/ with a new key
+    RotateKey,
+    /// Compare the latest benchmark run against an earlier one
+    Compare {
+        /// Label of the run to compare against [default: the previous run]
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Slowdown in percent above which a step counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Benchmark history file
+        #[arg(long, value_name = "PATH", default_value = history::DEFAULT_PATH)]
+        history: PathBuf,
+    },
+    /// Run a day again whenever its input, example or source code changes
+    Watch {
+        /// Day to run
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Run only the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file [default: input/dXX.txt]
+        input: Option<PathBuf>,
+
+        /// Also run the example dXX.txt in this directory
+        #[arg(
+            long,
+            value_name = "DIR",
+            num_args = 0..=1,
+            default_missing_value = "tests/examples"
+        )]
+        examples: Option<PathBuf>,
+
+        /// Change a puzzle constant of the day, e.g. `--param row=10` on day 15
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+    },
+    /// Check that every implementation of a day gives the same answers
+    CrossCheck {
+        /// Day to check
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Check only the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file, `-` reads stdin [default: input/dXX.txt]
+        input: Option<PathBuf>,
+
+        /// Change a puzzle constant of the day, e.g. `--param row=10` on day 15
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+
+        /// Check every day with more than one implementation against its default input
+        #[arg(long, conflicts_with_all = ["day", "input", "params"])]
+        all: bool,
+    },
+}
+
+/// Splits a `NAME=VALUE` parameter, the value is parsed by the day.
+fn parse_param(arg: &str) -> Result<(String, String), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got `{arg}`"))?;
+
+    Ok((name.to_owned(), value.to_owned()))
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Human-readable
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Time parsing and solving over many runs instead of running once
+    #[arg(long)]
+    bench: bool,
+
+    /// Untimed runs before measuring
+    #[arg(long, requires = "bench", default_value_t = 3)]
+    warmup: usize,
+
+    /// Number of timed runs [default: as many as fit in --budget]
+    #[arg(long, requires = "bench", value_parser = clap::value_parser!(u64).range(1..))]
+    runs: Option<u64>,
+
+    /// Time spent measuring each step, in milliseconds
+    #[arg(
+        long,
+        requires = "bench",
+        conflicts_with = "runs",
+        default_value_t = 500
+    )]
+    budget: u64,
+
+    /// File the median times are appended to
+    #[arg(long, requires = "bench", value_name = "PATH", default_value = history::DEFAULT_PATH)]
+    history: PathBuf,
+
+    /// Name of this run, to compare against later with `compare --baseline`
+    #[arg(long, requires = "bench")]
+    label: Option<String>,
+}
+
+impl BenchArgs {
+    fn config(&self) -> Option<BenchConfig> {
+        self.bench.then(|| BenchConfig {
+            warmup: self.warmup,
+            runs: self.runs.map(|runs| runs as usize),
+            budget: Duration::from_millis(self.budget),
+        })
+    }
+}
+
+/// Path of the input for `day`, downloaded if possible, failing when there is none.
+#[anyhoo::anyhoo]
+fn require_input(store: &InputStore, day: u8) -> PathBuf {
+    match store.resolve(day)? {
+        Some(path) => path,
+        None => anyhow::bail!(
+            "No input for day {day}, put it in {}/{} or set AOC_SESSION to download it",
+            input::LOCAL_DIR,
+            input::file_name(day)
+        ),
+    }
+}
+
+/// How `path` is referred to in diagnostics.
+fn display_path(path: &Path) -> &Path {
+    if path == Path::new("-") {
+        Path::new("<stdin>")
+    } else {
+        path
+    }
+}
+
+#[anyhoo::anyhoo]
+fn read_input(path: &Path) -> String {
+    let mut s = String::new();
+
+    if path == Path::new("-") {
+        std::io::stdin().read_to_string(&mut s)?;
+    } else {
+        s = input::read(path)?;
+    }
+
+    s
+}
+
+/// What to run for each solution, and how to report it.
+struct RunOptions {
+    parts: Vec<Part>,
+    bench: Option<BenchConfig>,
+    format: Format,
+}
+
+/// A single answer, as printed by `--format json`.
+#[derive(Serialize)]
+struct AnswerRecord<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    parse_ns: u64,
+    solve_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_alloc: Option<AllocStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solve_alloc: Option<AllocStats>,
+    input_path: &'a str,
+}
+
+/// Timings of a single step, as printed by `--format json --bench`.
+#[derive(Serialize)]
+struct BenchRecord<'a> {
+    day: u8,
+    step: &'a str,
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    stddev_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alloc: Option<AllocStats>,
+    input_path: &'a str,
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos() as u64
+}
+
+#[anyhoo::anyhoo]
+fn print_json(record: &impl Serialize) {
+    println!("{}", serde_json::to_string(record)?);
+}
+
+fn print_bench_header() {
+    print!(
+        "{:>3} {:<5} {:>8} {:>12} {:>12} {:>12} {:>12}",
+        "day", "step", "runs", "min[us]", "median[us]", "mean[us]", "stddev[us]"
+    );
+
+    if alloc_stats::ENABLED {
+        print!(" {:>10} {:>10} {:>10}", "allocs", "allocated", "peak");
+    }
+
+    println!();
+}
+
+#[anyhoo::anyhoo]
+fn print_bench_row(
+    format: Format,
+    day: u8,
+    step: &str,
+    stats: &Stats,
+    alloc: Option<AllocStats>,
+    input_path: &str,
+) {
+    let micros = |d: Duration| d.as_secs_f64() * 1e6;
+
+    match format {
+        Format::Text => {
+            print!(
+                "{day:>3} {step:<5} {:>8} {:>12.3} {:>12.3} {:>12.3} {:>12.3}",
+                stats.runs,
+                micros(stats.min),
+                micros(stats.median),
+                micros(stats.mean),
+                micros(stats.stddev),
+            );
+
+            if let Some(alloc) = alloc {
+                print!(
+                    " {:>10} {:>10} {:>10}",
+                    alloc.allocations,
+                    alloc_stats::format_bytes(alloc.bytes),
+                    alloc_stats::format_bytes(alloc.peak),
+                );
+            }
+
+            println!();
+        }
+        Format::Json => print_json(&BenchRecord {
+            day,
+            step,
+            runs: stats.runs,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            mean_ns: nanos(stats.mean),
+            stddev_ns: nanos(stats.stddev),
+            alloc,
+            input_path,
+        })?,
+    }
+}
+
+#[anyhoo::anyhoo]
+fn bench_solution(
+    solution: &dyn DynSolution,
+    input: &str,
+    parsed: &dyn Any,
+    input_path: &str,
+    config: &BenchConfig,
+    options: &RunOptions,
+) -> Vec<(String, Stats)> {
+    let day = solution.day();
+    let mut result = vec![];
+
+    let (_, alloc) = count_allocations(|| solution.parse_dyn(input));
+    let stats = bench::measure(config, || solution.parse_dyn(input));
+    print_bench_row(options.format, day, "parse", &stats, alloc, input_path)?;
+    result.push(("parse".to_owned(), stats));
+
+    for &part in &options.parts {
+        let (answer, alloc) = count_allocations(|| solution.solve_dyn(parsed, part));
+        answer?;
+        let stats = bench::measure(config, || solution.solve_dyn(parsed, part));
+        let step = format!("part{part}");
+        print_bench_row(options.format, day, &step, &stats, alloc, input_path)?;
+        result.push((step, stats));
+    }
+
+    result
+}
+
+/// Runs `f`, counting its allocations when built with the `alloc-stats` feature.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let (result, stats) = alloc_stats::measure(f);
+
+    (result, alloc_stats::ENABLED.then_some(stats))
+}
+
+/// Cost of a single step.
+#[derive(Clone, Copy)]
+struct Cost {
+    time: Duration,
+    alloc: Option<AllocStats>,
+}
+
+#[anyhoo::anyhoo]
+fn parse_timed(solution: &dyn DynSolution, input: &str, path: &Path) -> (Box<dyn Any>, Cost) {
+    let start = Instant::now();
+    let (parsed, alloc) = count_allocations(|| solution.parse_dyn(input));
+    let time = start.elapsed();
+    let parsed = parsed.map_err(|mut e| {
+        if let Some(parse_error) = e.downcast_mut::<ParseError>() {
+            parse_error.set_file(display_path(path));
+        }
+
+        e
+    })?;
+
+    (parsed, Cost { time, alloc })
+}
+
+#[anyhoo::anyhoo]
+fn solve_timed(solution: &dyn DynSolution, parsed: &dyn Any, part: Part) -> (String, Cost) {
+    let start = Instant::now();
+    let (answer, alloc) = count_allocations(|| solution.solve_dyn(parsed, part));
+
+    (
+        answer?,
+        Cost {
+            time: start.elapsed(),
+            alloc,
+        },
+    )
+}
+
+/// Runs `solution` on the input at `path`, returning the timings of every step when benchmarking.
+#[anyhoo::anyhoo]
+fn run_solution(
+    solution: &dyn DynSolution,
+    path: &Path,
+    options: &RunOptions,
+) -> Vec<(String, Stats)> {
+    let input_name = display_path(path).display().to_string();
+    let input = read_input(path)?;
+    let (parsed, parse_cost) = parse_timed(solution, &input, path)?;
+
+    if let Some(config) = &options.bench {
+        return bench_solution(
+            solution,
+            &input,
+            parsed.as_ref(),
+            &input_name,
+            config,
+            options,
+        );
+    }
+
+    for (i, &part) in options.parts.iter().enumerate() {
+        let (answer, solve_cost) = solve_timed(solution, parsed.as_ref(), part)?;
+
+        match options.format {
+            Format::Text => {
+                println!("Day {}: {} - Part {part}", solution.day(), solution.title());
+                println!("{answer}");
+                eprintln!("Elapsed: {:?}", solve_cost.time);
+
+                if let (Some(parse), Some(solve)) = (parse_cost.alloc, solve_cost.alloc) {
+                    if i == 0 {
+                        eprintln!("Parsing allocated: {parse}");
+                    }
+                    eprintln!("Solving allocated: {solve}");
+                }
+            }
+            Format::Json => print_json(&AnswerRecord {
+                day: solution.day(),
+                part: part.number(),
+                answer: &answer,
+                parse_ns: nanos(parse_cost.time),
+                solve_ns: nanos(solve_cost.time),
+                parse_alloc: parse_cost.alloc,
+                solve_alloc: solve_cost.alloc,
+                input_path: &input_name,
+            })?,
+        }
+    }
+
+    vec![]
+}
+
+/// Where the answer column of the summary table starts, for multi-line answers.
+const ANSWER_COLUMN: usize = 37;
+
+/// Runs every day with an input available, printing answers and timings as a single table.
+#[anyhoo::anyhoo]
+fn run_summary(store: &InputStore, parts: &[Part]) {
+    let mut total = Duration::ZERO;
+    let mut skipped = 0;
+    let mut failed = 0;
+
+    print!(
+        "{:>3} {:<26} {:>4}  {:<20} {:>10} {:>10}",
+        "day", "title", "part", "answer", "parse", "solve"
+    );
+
+    // peak heap usage of each step, the rest is in the single-day and JSON output
+    if alloc_stats::ENABLED {
+        print!(" {:>10} {:>10}", "parse heap", "solve heap");
+    }
+
+    println!();
+
+    for &solution in days::ALL {
+        let day = solution.day();
+
+        let path = match store.resolve(day) {
+            Ok(Some(path)) => path,
+            Ok(None) => {
+                println!(
+                    "{day:>3} {:<26} {:>4}  skipped, no input",
+                    solution.title(),
+                    "-"
+                );
+                skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                println!(
+                    "{day:>3} {:<26} {:>4}  failed: {e:#}",
+                    solution.title(),
+                    "-"
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        let parsed = read_input(&path).and_then(|input| parse_timed(solution, &input, &path));
+        let (parsed, parse_cost) = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!(
+                    "{day:>3} {:<26} {:>4}  failed: {e:#}",
+                    solution.title(),
+                    "-"
+                );
+                failed += 1;
+                continue;
+            }
+        };
+        total += parse_cost.time;
+        let mut day_failed = false;
+
+        for (i, &part) in parts.iter().enumerate() {
+            let title = if i == 0 { solution.title() } else { "" };
+
+            let (answer, solve_cost) = match solve_timed(solution, parsed.as_ref(), part) {
+                Ok(solved) => solved,
+                Err(e) => {
+                    println!("{day:>3} {title:<26} {:>4}  failed: {e:#}", part.number());
+                    day_failed = true;
+                    continue;
+                }
+            };
+            total += solve_cost.time;
+
+            let parse = if i == 0 {
+                format!("{:.1?}", parse_cost.time)
+            } else {
+                String::new()
+            };
+            let mut lines = answer.lines();
+
+            print!(
+                "{day:>3} {title:<26} {:>4}  {:<20} {parse:>10} {:>10}",
+                part.number(),
+                lines.next().unwrap_or_default(),
+                format!("{:.1?}", solve_cost.time),
+            );
+
+            if let (Some(parse), Some(solve)) = (parse_cost.alloc, solve_cost.alloc) {
+                let parse = if i == 0 {
+                    alloc_stats::format_bytes(parse.peak)
+                } else {
+                    String::new()
+                };
+
+                print!(" {parse:>10} {:>10}", alloc_stats::format_bytes(solve.peak));
+            }
+
+            println!();
+
+            for line in lines {
+                println!("{:ANSWER_COLUMN$}{line}", "");
+            }
+        }
+
+        if day_failed {
+            failed += 1;
+        }
+    }
+
+    let ran = days::ALL.len() - skipped - failed;
+    println!();
+    println!("Total: {total:.1?} over {ran} days, {skipped} skipped, {failed} failed");
+
+    if failed > 0 {
+        anyhow::bail!("Failed to run {failed} of {} days", days::ALL.len());
+    }
+}
+
+/// Appends the median of every benchmarked step to the history at `path`.
+#[anyhoo::anyhoo]
+fn record_history(
+    path: &Path,
+    label: Option<String>,
+    params: &[(String, String)],
+    timings: Vec<(u8, String, (String, Stats))>,
+) {
+    let run = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    let commit = history::current_commit();
+
+    let entries: Vec<_> = timings
+        .into_iter()
+        .map(|(day, input, (step, stats))| history::Entry {
+            run,
+            commit: commit.clone(),
+            label: label.clone(),
+            day,
+            input,
+            params: params.to_vec(),
+            step,
+            median_ns: nanos(stats.median),
+        })
+        .collect();
+
+    history::append(path, &entries)?;
+}
+
+fn describe_run(run: &Run) -> String {
+    let mut result = format!("run {}", run.id);
+
+    if let Some(commit) = run.commit {
+        result += &format!(" at {commit}");
+    }
+
+    if let Some(label) = run.label {
+        result += &format!(" ({label})");
+    }
+
+    result
+}
+
+/// Prints how every step of the latest benchmark run changed, failing if any got slower by more
+/// than `threshold` percent.
+#[anyhoo::anyhoo]
+fn compare_runs(path: &Path, baseline: Option<&str>, threshold: f64) {
+    let entries = history::load(path)?;
+    let runs = history::runs(&entries);
+
+    let (latest, base) = history::select(&runs, baseline).ok_or_else(|| match baseline {
+        Some(label) => anyhow::anyhow!("No earlier run labelled {label:?} in {}", path.display()),
+        None => anyhow::anyhow!("Fewer than two benchmark runs in {}", path.display()),
+    })?;
+
+    println!("latest:   {}", describe_run(latest));
+    println!("baseline: {}", describe_run(base));
+    println!();
+    println!(
+        "{:>3} {:<5} {:>14} {:>14} {:>8}",
+        "day", "step", "baseline[us]", "latest[us]", "change"
+    );
+
+    let mut regressions = 0;
+
+    for change in history::compare(latest, base) {
+        let micros = |ns: u64| ns as f64 / 1e3;
+        let flag = if change.is_regression(threshold) {
+            regressions += 1;
+            "  SLOWER"
+        } else {
+            ""
+        };
+
+        println!(
+            "{:>3} {:<5} {:>14.3} {:>14.3} {:>+7.1}%{flag}",
+            change.day,
+            change.step,
+            micros(change.baseline_ns),
+            micros(change.latest_ns),
+            change.relative() * 100.0,
+        );
+    }
+
+    if regressions > 0 {
+        anyhow::bail!("{regressions} steps got more than {threshold}% slower");
+    }
+}
+
+/// Key file location, an error if there is none.
+fn key_path() -> anyhow::Result<PathBuf> {
+    vault::default_key_path()
+        .ok_or_else(|| anyhow::anyhow!("No cache directory for the key file, set AOC_INPUT_KEY"))
+}
+
+/// Inputs in `input/` to encrypt or decrypt: the one of `day`, or every existing one.
+fn local_inputs(day: Option<u8>, encrypted: bool) -> Vec<PathBuf> {
+    let path = |day| {
+        let path = Path::new(input::LOCAL_DIR).join(input::file_name(day));
+
+        if encrypted {
+            vault::encrypted_path(&path)
+        } else {
+            path
+        }
+    };
+
+    match day {
+        Some(day) => vec![path(day)],
+        None => (1..=25).map(path).filter(|path| path.is_file()).collect(),
+    }
+}
+
+/// Encrypts inputs in `input/`, generating a key first if there is none.
+#[anyhoo::anyhoo]
+fn encrypt_inputs(day: Option<u8>, keep: bool) {
+    let key_path = key_path()?;
+    let key = if key_path.exists() {
+        Key::load(&key_path)?
+    } else {
+        let key = Key::generate();
+        key.save(&key_path)?;
+        eprintln!(
+            "Created a new key in {}, keep a copy of it somewhere safe",
+            key_path.display()
+        );
+
+        key
+    };
+
+    for path in local_inputs(day, false) {
+        let encrypted = vault::encrypt_file(&key, &path)?;
+        if !keep {
+            std::fs::remove_file(&path)
+                .map_err(|e| anyhow::anyhow!("Cannot remove {}: {e}", path.display()))?;
+        }
+
+        println!("{}", encrypted.display());
+    }
+}
+
+/// Decrypts inputs in `input/` next to the encrypted files.
+#[anyhoo::anyhoo]
+fn decrypt_inputs(day: Option<u8>) {
+    let key = Key::load(&key_path()?)?;
+
+    for path in local_inputs(day, true) {
+        println!("{}", vault::decrypt_file(&key, &path)?.display());
+    }
+}
+
+/// Submits the answer to a part, solving it first if no `answer` is given.
+#[anyhoo::anyhoo]
+fn submit_answer(day: u8, part: Part, answer: Option<String>, log: Option<PathBuf>) {
+    let log_path = log
+        .or_else(|| input::default_cache_dir().map(|dir| dir.join("submissions.jsonl")))
+        .ok_or_else(|| anyhow::anyhow!("No cache directory to keep submissions in, pass --log"))?;
+    let mut log = SubmissionLog::open(&log_path)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = days::get(day)
+                .ok_or_else(|| anyhow::anyhow!("No solution registered for day {day}"))?;
+            let path = require_input(&InputStore::from_env(), day)?;
+            let input = read_input(&path)?;
+            let (parsed, _) = parse_timed(solution, &input, &path)?;
+
+            solution.solve_dyn(parsed.as_ref(), part)?
+        }
+    };
+
+    let client =
+        Client::from_env().ok_or_else(|| anyhow::anyhow!("Set AOC_SESSION to submit answers"))?;
+    let submitter = HttpSubmitter::new(client);
+
+    match log.submit(&submitter, day, part, &answer)? {
+        Verdict::Sent(outcome) => println!("Day {day} part {part}: {answer} is {outcome}"),
+        Verdict::Repeated(outcome) => {
+            println!("Day {day} part {part}: {answer} was submitted before, it is {outcome}")
+        }
+        Verdict::Solved(solution) => {
+            println!("Day {day} part {part} is already solved, the answer is {solution}")
+        }
+    }
+}
+
+/// Runs every implementation of `day` on the input at `path`, printing how they disagree.
+///
+/// Returns the number of diverging answers.
+#[anyhoo::anyhoo]
+fn cross_check_input(day: u8, path: &Path, parts: &[Part], params: &[(String, String)]) -> usize {
+    let input = read_input(path)?;
+    let divergences = cross_check::cross_check(day, &input, parts, params).map_err(|mut e| {
+        if let Some(parse_error) = e.downcast_mut::<ParseError>() {
+            parse_error.set_file(display_path(path));
+        }
+
+        e
+    })?;
+
+    let variants = days::implementations(day)
+        .iter()
+        .map(|s| s.variant())
+        .collect::<Vec<_>>();
+
+    if variants.len() < 2 {
+        println!("Day {day}: only one implementation, nothing to compare");
+    } else if divergences.is_empty() {
+        println!("Day {day}: {} agree", variants.join(", "));
+    }
+    for divergence in &divergences {
+        println!("{divergence}");
+    }
+
+    divergences.len()
+}
+
+/// Results of the run before `aoc watch` restarted itself after a rebuild, as JSON.
+const WATCH_RESULTS_VAR: &str = "AOC_WATCH_RESULTS";
+
+/// Runs `solution` on the input at `path`, printing answers and timings next to those of the
+/// `previous` run.
+#[anyhoo::anyhoo]
+fn run_watched(
+    solution: &dyn DynSolution,
+    path: &Path,
+    parts: &[Part],
+    previous: Option<&RunResult>,
+) -> RunResult {
+    let input = read_input(path)?;
+    let (parsed, parse_cost) = parse_timed(solution, &input, path)?;
+    let mut result = RunResult {
+        parse_time: parse_cost.time,
+        ..RunResult::default()
+    };
+
+    println!(
+        "Day {} on {}, parsed in {}",
+        solution.day(),
+        path.display(),
+        watch::time_change(previous.map(|p| p.parse_time), parse_cost.time)
+    );
+
+    for &part in parts {
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            solve_timed(solution, parsed.as_ref(), part)
+        }));
+        let (answer, solve_cost) = match solved {
+            Ok(Ok(solved)) => solved,
+            Ok(Err(e)) => {
+                println!("Part {part}: {e:#}");
+                continue;
+            }
+            Err(_) => {
+                println!("Part {part}: panicked");
+                continue;
+            }
+        };
+
+        let before = previous.and_then(|p| p.answers.get(&part.number()));
+        let status = [
+            watch::answer_change(before.map(|(answer, _)| answer.as_str()), &answer),
+            Some(watch::time_change(
+                before.map(|&(_, time)| time),
+                solve_cost.time,
+            )),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+
+        if answer.contains('\n') {
+            println!("Part {part} ({status}):\n{answer}");
+        } else {
+            println!("Part {part}: {answer} ({status})");
+        }
+
+        result
+            .answers
+            .insert(part.number(), (answer, solve_cost.time));
+    }
+
+    result
+}
+
+/// Rebuilds `aoc` from the sources it was built from, returning the path of the new binary.
+#[anyhoo::anyhoo]
+fn rebuild() -> PathBuf {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut build = Process::new(cargo);
+    build
+        .args([
+            "build",
+            "--bin",
+            "aoc",
+            "--message-format=json-render-diagnostics",
+        ])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .stderr(Stdio::inherit());
+
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    if alloc_stats::ENABLED {
+        build.args(["--features", "alloc-stats"]);
+    }
+
+    let output = build.output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "Build failed, still running the old code"
+    );
+
+    let executable = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| message["target"]["name"] == "aoc")
+        .find_map(|message| message["executable"].as_str().map(PathBuf::from));
+
+    executable.ok_or_else(|| anyhow::anyhow!("Cargo did not report where it built aoc"))?
+}
+
+/// Replaces this process with `executable` run with the same arguments, handing over `results`.
+#[anyhoo::anyhoo]
+fn restart(executable: &Path, results: &[Option<RunResult>]) {
+    let mut process = Process::new(executable);
+    process
+        .args(std::env::args_os().skip(1))
+        .env(WATCH_RESULTS_VAR, serde_json::to_string(results)?);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+
+        Err(process.exec())?;
+    }
+
+    #[cfg(not(unix))]
+    std::process::exit(process.status()?.code().unwrap_or(1));
+}
+
+/// Runs `day` on `inputs` again and again, whenever one of them or the source code changes.
+#[anyhoo::anyhoo]
+fn watch_day(day: u8, inputs: &[PathBuf], parts: &[Part], params: &[(String, String)]) {
+    let solution = days::get(day)
+        .ok_or_else(|| anyhow::anyhow!("No solution registered for day {day}"))?
+        .with_params(params)?;
+
+    // only watch the sources when they are where this binary was built from
+    let sources = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let sources: Vec<_> = sources.is_dir().then_some(sources).into_iter().collect();
+    let watcher = FileWatcher::new(inputs, &sources)?;
+
+    let mut previous: Vec<Option<RunResult>> = match std::env::var(WATCH_RESULTS_VAR) {
+        Ok(results) => serde_json::from_str(&results).unwrap_or_default(),
+        Err(_) => vec![],
+    };
+    previous.resize(inputs.len(), None);
+
+    loop {
+        for (path, previous) in inputs.iter().zip(&mut previous) {
+            match run_watched(solution.as_ref(), path, parts, previous.as_ref()) {
+                Ok(result) => *previous = Some(result),
+                Err(e) => println!("{e:#}"),
+            }
+        }
+
+        eprintln!("Waiting for changes...");
+        let changed = watcher.wait()?;
+        println!();
+
+        if changed
+            .iter()
+            .any(|path| sources.iter().any(|dir| path.starts_with(dir)))
+        {
+            eprintln!("Sources changed, rebuilding");
+
+            match rebuild() {
+                Ok(executable) => restart(&executable, &previous)?,
+                Err(e) => eprintln!("{e:#}"),
+            }
+        }
+    }
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            params,
+            all,
+            format,
+            bench,
+        } => {
+            let parts = match part.and_then(Part::from_number) {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+            let options = RunOptions {
+                parts,
+                bench: bench.config(),
+                format,
+            };
+            let text = matches!(format, Format::Text);
+            let store = InputStore::from_env();
+
+            if all && text && options.bench.is_none() {
+                run_summary(&store, &options.parts)?;
+                return Ok(());
+            }
+
+            if options.bench.is_some() && text {
+                print_bench_header();
+            }
+
+            let mut timings = vec![];
+            let mut failed = 0;
+
+            if all {
+                for &solution in days::ALL {
+                    let path = match store.resolve(solution.day()) {
+                        Ok(Some(path)) => path,
+                        Ok(None) => {
+                            eprintln!("Skipping day {}, no input", solution.day());
+                            continue;
+                        }
+                        Err(e) => {
+                            eprintln!("Day {} failed: {e:#}", solution.day());
+                            failed += 1;
+                            continue;
+                        }
+                    };
+
+                    let steps = match run_solution(solution, &path, &options) {
+                        Ok(steps) => steps,
+                        Err(e) => {
+                            eprintln!("Day {} failed: {e:#}", solution.day());
+                            failed += 1;
+                            continue;
+                        }
+                    };
+                    let input = display_path(&path).display().to_string();
+                    timings.extend(
+                        steps
+                            .into_iter()
+                            .map(|step| (solution.day(), input.clone(), step)),
+                    );
+                }
+            } else {
+                let day = day.unwrap_or_default();
+                let solution = days::get(day)
+                    .ok_or_else(|| anyhow::anyhow!("No solution registered for day {day}"))?
+                    .with_params(&params)?;
+                let path = match input {
+                    Some(path) => path,
+                    None => require_input(&store, day)?,
+                };
+
+                let steps = run_solution(solution.as_ref(), &path, &options)?;
+                let input = display_path(&path).display().to_string();
+                timings.extend(steps.into_iter().map(|step| (day, input.clone(), step)));
+            }
+
+            if options.bench.is_some() {
+                record_history(&bench.history, bench.label, &params, timings)?;
+            }
+
+            if failed > 0 {
+                anyhow::bail!("Failed to run {failed} of {} days", days::ALL.len());
+            }
+        }
+        Command::Compare {
+            baseline,
+            threshold,
+            history,
+        } => compare_runs(&history, baseline.as_deref(), threshold)?,
+        Command::CrossCheck {
+            day,
+            part,
+            input,
+            params,
+            all,
+        } => {
+            let parts = match part.and_then(Part::from_number) {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+            let store = InputStore::from_env();
+            let mut diverging = 0;
+
+            if all {
+                let mut checked = days::VARIANTS.iter().map(|s| s.day()).collect::<Vec<_>>();
+                checked.dedup();
+
+                for day in checked {
+                    let Some(path) = store.resolve(day)? else {
+                        eprintln!("Skipping day {day}, no input");
+                        continue;
+                    };
+
+                    diverging += cross_check_input(day, &path, &parts, &params)?;
+                }
+            } else {
+                let day = day.unwrap_or_default();
+                let path = match input {
+                    Some(path) => path,
+                    None => require_input(&store, day)?,
+                };
+
+                diverging += cross_check_input(day, &path, &parts, &params)?;
+            }
+
+            if diverging > 0 {
+                anyhow::bail!("Implementations disagree on {diverging} answers");
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            log,
+        } => {
+            let part = Part::from_number(part).unwrap_or(Part::One);
+
+            submit_answer(day, part, answer, log)?;
+        }
+        Command::Encrypt { day, all: _, keep } => encrypt_inputs(day, keep)?,
+        Command::Decrypt { day, all: _ } => decrypt_inputs(day)?,
+        Command::RotateKey => {
+            let key_path = key_path()?;
+            let rotated = vault::rotate_key(&key_path, Path::new(input::LOCAL_DIR))?;
+
+            println!(
+                "Re-encrypted {} inputs with a new key in {}",
+                rotated.len(),
+                key_path.display()
+            );
+        }
+        Command::Watch {
+            day,
+            part,
+            input,
+            examples,
+            params,
+        } => {
+            let parts = match part.and_then(Part::from_number) {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+            let mut inputs = vec![match input {
+                Some(path) => path,
+                None => require_input(&InputStore::from_env(), day)?,
+            }];
+
+            if let Some(dir) = examples {
+                inputs.push(dir.join(input::file_name(day)));
+            }
+
+            anyhow::ensure!(
+                !inputs.contains(&PathBuf::from("-")),
+                "Cannot watch stdin, pass an input file"
+            );
+
+            watch_day(day, &inputs, &parts, &params)?;
+        }
+        Command::Fetch { day, all: _ } => {
+            let store = InputStore::from_env();
+            let days = match day {
+                Some(day) => vec![day],
+                None => days::ALL.iter().map(|s| s.day()).collect(),
+            };
+
+            for day in days {
+                println!("{}", require_input(&store, day)?.display());
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Error reporting and input handling shared by the input parsers.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+/// Malformed puzzle input, located by line and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<PathBuf>,
+    line: usize,
+    column: usize,
+    text: String,
+    reason: String,
+}
+
+impl ParseError {
+    /// Error about `fragment`, which has to be a subslice of `input`.
+    pub fn at(input: &str, fragment: &str, reason: impl Into<String>) -> Self {
+        let (line, column) = locate(input, fragment);
+
+        Self {
+            file: None,
+            line,
+            column,
+            text: fragment.to_owned(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Error about something missing from the end of `input`.
+    pub fn at_end(input: &str, reason: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], reason)
+    }
+
+    /// Moves an error located relative to `base` so it is relative to `input` instead.
+    ///
+    /// Lets helpers that only see a part of the input report errors without knowing the rest.
+    pub fn within(self, input: &str, base: &str) -> Self {
+        let (base_line, base_column) = locate(input, base);
+
+        let column = if self.line == 1 {
+            base_column + self.column - 1
+        } else {
+            self.column
+        };
+
+        Self {
+            line: base_line + self.line - 1,
+            column,
+            ..self
+        }
+    }
+
+    /// Names the file the input was read from.
+    pub fn set_file(&mut self, path: &Path) {
+        self.file = Some(path.to_owned());
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// 1-based line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The offending part of the input.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => write!(f, "<input>")?,
+        }
+
+        write!(f, ":{}:{}: {}", self.line, self.column, self.reason)?;
+
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Turns a missing value or a failed conversion into a [`ParseError`] at `fragment`.
+pub trait ParseContext<T> {
+    fn or_parse_error(self, input: &str, fragment: &str, reason: &str) -> Result<T, ParseError>;
+}
+
+impl<T> ParseContext<T> for Option<T> {
+    fn or_parse_error(self, input: &str, fragment: &str, reason: &str) -> Result<T, ParseError> {
+        self.ok_or_else(|| ParseError::at(input, fragment, reason))
+    }
+}
+
+impl<T, E: Display> ParseContext<T> for Result<T, E> {
+    fn or_parse_error(self, input: &str, fragment: &str, reason: &str) -> Result<T, ParseError> {
+        self.map_err(|e| ParseError::at(input, fragment, format!("{reason} ({e})")))
+    }
+}
+
+/// Input in the form the parsers expect, whatever editor or browser it was saved with.
+///
+/// Drops a byte order mark, turns `\r\n` into `\n`, trims trailing whitespace off every line,
+/// removes trailing blank lines and ends the last line with a single `\n`.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let body = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let mut lines: Vec<_> = body.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let mut result = lines.join("\n");
+    if !result.is_empty() {
+        result.push('\n');
+    }
+
+    if result == input {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(result)
+}
+
+/// Blocks of consecutive non-blank lines, without their line endings.
+///
+/// Any number of blank or whitespace-only lines separate blocks, also at the start and end of the
+/// input. Blocks are slices of `input`, so [`ParseError::at`] can point into them.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> + '_ {
+    let mut rest = input;
+
+    std::iter::from_fn(move || {
+        let start = loop {
+            let (line, tail) = split_line(rest)?;
+            if !line.trim().is_empty() {
+                break rest;
+            }
+            rest = tail;
+        };
+
+        let mut len = 0;
+        while let Some((line, tail)) = split_line(rest) {
+            if line.trim().is_empty() {
+                break;
+            }
+            len = line.as_ptr() as usize - start.as_ptr() as usize + line.len();
+            rest = tail;
+        }
+
+        Some(&start[..len])
+    })
+}
+
+/// First line of `input` without its line ending, and the rest; `None` once `input` is empty.
+fn split_line(input: &str) -> Option<(&str, &str)> {
+    if input.is_empty() {
+        return None;
+    }
+
+    let (line, tail) = input.split_once('\n').unwrap_or((input, ""));
+
+    Some((line.strip_suffix('\r').unwrap_or(line), tail))
+}
+
+/// 1-based line and column where `fragment` starts within `input`.
+fn locate(input: &str, fragment: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let offset = (fragment.as_ptr() as usize).saturating_sub(start);
+    debug_assert!(offset <= input.len(), "fragment is not a part of the input");
+    let offset = offset.min(input.len());
+
+    let before = &input.as_bytes()[..offset];
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let line_start = before
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+
+    let column = input
+        .get(line_start..offset)
+        .map_or(offset - line_start, |s| s.chars().count())
+        + 1;
+
+    (line, column)
+}
@@ -0,0 +1,195 @@
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::parse;
+
+/// One of the two parts of a day's puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Parses `value` as the new value of the parameter `name`.
+pub fn parse_param<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid value {value:?} for parameter `{name}`: {e}"))
+}
+
+/// Parses `value` as the new value of the parameter `name`, which has to be at least 1.
+pub fn positive_param(name: &str, value: &str) -> Result<usize> {
+    let n = parse_param(name, value)?;
+    anyhow::ensure!(n > 0, "Parameter `{name}` has to be at least 1");
+
+    Ok(n)
+}
+
+/// What a part returns: an answer, or why the input has none.
+pub trait Answer {
+    fn into_answer(self) -> Result<String>;
+}
+
+macro_rules! displayed_answers {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn into_answer(self) -> Result<String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+displayed_answers!(u64, i64, usize, isize, String);
+
+/// For inputs that parse but turn out to have no answer, such as a map without a path.
+impl<T: Answer> Answer for Result<T> {
+    fn into_answer(self) -> Result<String> {
+        self?.into_answer()
+    }
+}
+
+/// A single day's puzzle: one parse step shared by both parts.
+///
+/// Puzzle constants that differ between the examples and the real input, such as the row
+/// scanned on day 15, are fields of the solver and exposed as parameters.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Name telling apart several implementations of the same day.
+    const VARIANT: &'static str = "default";
+
+    /// Puzzle input after parsing.
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// Parses the raw puzzle input.
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1;
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2;
+
+    /// Names and current values of the parameters.
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    /// Changes the parameter `name`, one of those listed by [`Solution::params`].
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<()> {
+        anyhow::bail!("No parameter `{name}`")
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days with different input types can share a registry.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn variant(&self) -> &'static str;
+
+    /// Parses the input after [normalizing](parse::normalize) it.
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Fails if the input has no answer. Panics if `input` did not come from
+    /// [`DynSolution::parse_dyn`] of the same solution.
+    fn solve_dyn(&self, input: &dyn Any, part: Part) -> Result<String>;
+
+    /// Copy of the solution with the given `(name, value)` parameters changed.
+    fn with_params(&self, params: &[(String, String)]) -> Result<Box<dyn DynSolution>>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Clone + 'static,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn variant(&self) -> &'static str {
+        S::VARIANT
+    }
+
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>> {
+        let parsed = self.parse(&parse::normalize(input))?;
+
+        Ok(Box::new(parsed))
+    }
+
+    fn solve_dyn(&self, input: &dyn Any, part: Part) -> Result<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by a different solution");
+
+        match part {
+            Part::One => self.part1(input).into_answer(),
+            Part::Two => self.part2(input).into_answer(),
+        }
+    }
+
+    fn with_params(&self, params: &[(String, String)]) -> Result<Box<dyn DynSolution>> {
+        let mut result = self.clone();
+        let known = self.params();
+
+        for (name, value) in params {
+            if !known.iter().any(|(known, _)| known == name) {
+                let names: Vec<_> = known.iter().map(|(name, _)| *name).collect();
+
+                match names[..] {
+                    [] => anyhow::bail!("Day {} has no parameters", S::DAY),
+                    _ => anyhow::bail!(
+                        "Day {} has no parameter `{name}`, expected one of: {}",
+                        S::DAY,
+                        names.join(", ")
+                    ),
+                }
+            }
+
+            result.set_param(name, value)?;
+        }
+
+        Ok(Box::new(result))
+    }
+}
@@ -0,0 +1,260 @@
+//! Sending answers to the website and remembering what it said about them.
+
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::solution::Part;
+
+/// What the website made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Answered too recently, the message tells how long to wait.
+    RateLimited(Option<String>),
+    /// The part is either already solved or not unlocked yet.
+    WrongLevel,
+    /// A response that could not be understood, with its text.
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Whether the answer was judged, so sending it again would get the same outcome.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Correct | Self::Incorrect | Self::TooHigh | Self::TooLow
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Incorrect => write!(f, "incorrect"),
+            Self::TooHigh => write!(f, "incorrect, too high"),
+            Self::TooLow => write!(f, "incorrect, too low"),
+            Self::RateLimited(Some(wait)) => write!(f, "rate limited, {wait}"),
+            Self::RateLimited(None) => write!(f, "rate limited"),
+            Self::WrongLevel => write!(f, "already solved or not unlocked yet"),
+            Self::Unknown(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
+
+/// Reads the outcome out of the page the website responds with.
+pub fn parse_response(page: &str) -> Outcome {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| format!("{wait} left to wait"));
+
+        Outcome::RateLimited(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// Text of the `<article>` element holding the response message, or of the whole page.
+fn article_text(page: &str) -> String {
+    let article = page
+        .find("<article")
+        .and_then(|start| {
+            let end = page[start..].find("</article>")?;
+            Some(&page[start..start + end])
+        })
+        .unwrap_or(page);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Destination of submitted answers.
+pub trait Submit {
+    fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome>;
+}
+
+/// Submits answers through the website's answer form.
+pub struct HttpSubmitter {
+    client: Client,
+}
+
+impl HttpSubmitter {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Submit for HttpSubmitter {
+    fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome> {
+        let url = self.client.day_url(day, "answer");
+        let level = part.number().to_string();
+
+        let page = self
+            .client
+            .request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| anyhow::anyhow!("Submitting to {url} failed: {e}"))?
+            .into_string()
+            .with_context(|| format!("Cannot read the response from {url}"))?;
+
+        Ok(parse_response(&page))
+    }
+}
+
+/// A submitted answer and what became of it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Result of [`SubmissionLog::submit`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer was sent, this is the response.
+    Sent(Outcome),
+    /// The same answer was judged before, it was not sent again.
+    Repeated(Outcome),
+    /// The part was solved before with the given answer, nothing was sent.
+    Solved(String),
+}
+
+/// Answers judged so far, kept in a JSON-lines file.
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Reads the log at `path`, a missing file is an empty log.
+    pub fn open(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("Cannot read {}", path.display())),
+        };
+
+        let submissions = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("{}:{}: invalid submission", path.display(), i + 1))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            path: path.to_owned(),
+            submissions,
+        })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// The correct answer of a part, if it was found.
+    pub fn solution(&self, day: u8, part: Part) -> Option<&str> {
+        self.submissions
+            .iter()
+            .find(|s| s.day == day && s.part == part.number() && s.outcome == Outcome::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// What was said about `answer` the last time it was judged.
+    pub fn previous_outcome(&self, day: u8, part: Part, answer: &str) -> Option<&Outcome> {
+        self.submissions
+            .iter()
+            .rev()
+            .find(|s| s.day == day && s.part == part.number() && s.answer == answer)
+            .map(|s| &s.outcome)
+    }
+
+    /// Sends `answer` with `submitter` unless the log already knows how it will be judged,
+    /// recording the outcome if it is final.
+    pub fn submit(
+        &mut self,
+        submitter: &dyn Submit,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict> {
+        if let Some(solution) = self.solution(day, part) {
+            return Ok(Verdict::Solved(solution.to_owned()));
+        }
+
+        if let Some(outcome) = self.previous_outcome(day, part, answer) {
+            return Ok(Verdict::Repeated(outcome.clone()));
+        }
+
+        let outcome = submitter.submit(day, part, answer)?;
+
+        if outcome.is_final() {
+            self.record(Submission {
+                day,
+                part: part.number(),
+                answer: answer.to_owned(),
+                outcome: outcome.clone(),
+            })?;
+        }
+
+        Ok(Verdict::Sent(outcome))
+    }
+
+    fn record(&mut self, submission: Submission) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Cannot open {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&submission)?)?;
+
+        self.submissions.push(submission);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+//! Puzzle inputs encrypted at rest, so they can be committed next to the solutions.
+//!
+//! An encrypted input is the plain file name with `.enc` appended, e.g. `input/d07.txt.enc`,
+//! holding a random nonce and the XChaCha20-Poly1305 ciphertext. The key is kept out of the
+//! repository in a key file: `AOC_INPUT_KEY`, or `input.key` in the
+//! [cache directory](crate::input::default_cache_dir) when that is not set.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+
+use crate::input;
+
+/// Extension appended to the names of encrypted files.
+pub const EXTENSION: &str = "enc";
+
+/// Start of every encrypted file, also telling the format version.
+const MAGIC: &[u8] = b"aoc2022-enc1";
+
+const NONCE_LEN: usize = 24;
+
+const KEY_LEN: usize = 32;
+
+/// Key file location, see the [module docs](self).
+pub fn default_key_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AOC_INPUT_KEY") {
+        return Some(path.into());
+    }
+
+    input::default_cache_dir().map(|dir| dir.join("input.key"))
+}
+
+/// Whether `path` names an encrypted file.
+pub fn is_encrypted(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == EXTENSION)
+}
+
+/// Where the encrypted copy of `path` goes.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(EXTENSION);
+
+    name.into()
+}
+
+/// Where the decrypted copy of the encrypted file `path` goes.
+pub fn decrypted_path(path: &Path) -> PathBuf {
+    path.with_extension("")
+}
+
+/// Secret key for encrypting inputs, stored as hex in the key file.
+pub struct Key(chacha20poly1305::Key);
+
+impl Key {
+    pub fn generate() -> Self {
+        Self(XChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let hex = hex.trim();
+        anyhow::ensure!(
+            hex.len() == KEY_LEN * 2 && hex.is_ascii(),
+            "A key is {} hex digits, got {}",
+            KEY_LEN * 2,
+            hex.len()
+        );
+
+        let mut key = chacha20poly1305::Key::default();
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits)?;
+            *byte = u8::from_str_radix(digits, 16)
+                .with_context(|| format!("Invalid hex digits {digits:?} in the key"))?;
+        }
+
+        Ok(Self(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let hex = fs::read_to_string(path)
+            .with_context(|| format!("Cannot read the key file {}", path.display()))?;
+
+        Self::from_hex(&hex).with_context(|| format!("Invalid key file {}", path.display()))
+    }
+
+    /// Writes the key file, readable only by its owner where the platform allows.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options
+            .open(path)
+            .with_context(|| format!("Cannot open {}", path.display()))?;
+        std::io::Write::write_all(&mut file, format!("{}\n", self.to_hex()).as_bytes())
+            .with_context(|| format!("Cannot write {}", path.display()))
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> Vec<u8> {
+        let cipher = XChaCha20Poly1305::new(&self.0);
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .expect("encryption only fails for inputs of many gigabytes");
+
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<String> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| anyhow::anyhow!("Not an encrypted input"))?;
+        anyhow::ensure!(data.len() >= NONCE_LEN, "Encrypted input is truncated");
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+        let plaintext = XChaCha20Poly1305::new(&self.0)
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow::anyhow!("Cannot decrypt, wrong key or corrupted file"))?;
+
+        String::from_utf8(plaintext).context("Decrypted input is not UTF-8")
+    }
+}
+
+/// Encrypts the file at `path` next to it, returning the path of the encrypted copy.
+pub fn encrypt_file(key: &Key, path: &Path) -> Result<PathBuf> {
+    let plaintext =
+        fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    let encrypted = encrypted_path(path);
+    write_atomic(&encrypted, &key.encrypt(&plaintext))?;
+
+    Ok(encrypted)
+}
+
+/// Decrypts the encrypted file at `path` next to it, returning the path of the plain copy.
+pub fn decrypt_file(key: &Key, path: &Path) -> Result<PathBuf> {
+    let plaintext = read(key, path)?;
+    let decrypted = decrypted_path(path);
+    write_atomic(&decrypted, plaintext.as_bytes())?;
+
+    Ok(decrypted)
+}
+
+/// Decrypts the encrypted file at `path` in memory.
+pub fn read(key: &Key, path: &Path) -> Result<String> {
+    let data = fs::read(path).with_context(|| format!("Cannot read {}", path.display()))?;
+
+    key.decrypt(&data)
+        .with_context(|| format!("Cannot decrypt {}", path.display()))
+}
+
+/// Re-encrypts every encrypted file in `dir` with a new key, replacing the key file.
+///
+/// All files are decrypted before anything is written, and the old key is kept next to the key
+/// file with `.old` appended until every file has been rewritten. Returns the rewritten files.
+pub fn rotate_key(key_path: &Path, dir: &Path) -> Result<Vec<PathBuf>> {
+    let old_key = Key::load(key_path)?;
+
+    let mut files = vec![];
+    for entry in fs::read_dir(dir).with_context(|| format!("Cannot list {}", dir.display()))? {
+        let path = entry?.path();
+        if is_encrypted(&path) {
+            let plaintext = read(&old_key, &path)?;
+            files.push((path, plaintext));
+        }
+    }
+    files.sort();
+
+    let mut backup = key_path.as_os_str().to_owned();
+    backup.push(".old");
+    let backup = PathBuf::from(backup);
+    fs::copy(key_path, &backup)
+        .with_context(|| format!("Cannot back up the key to {}", backup.display()))?;
+
+    let new_key = Key::generate();
+    new_key.save(key_path)?;
+
+    for (path, plaintext) in &files {
+        write_atomic(path, &new_key.encrypt(plaintext))?;
+    }
+
+    fs::remove_file(&backup).with_context(|| format!("Cannot remove {}", backup.display()))?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Writes through a temporary file, so `path` never holds half of its contents.
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+
+    fs::write(&temp, contents).with_context(|| format!("Cannot write {}", temp.display()))?;
+    fs::rename(&temp, path).with_context(|| format!("Cannot write {}", path.display()))
+}
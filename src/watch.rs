@@ -0,0 +1,173 @@
+//! Waiting for inputs or sources to change, and describing how answers changed, for `aoc watch`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use notify::event::{AccessKind, AccessMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+
+/// How long to wait for further changes after the first one, as editors often write a file in
+/// several steps.
+pub const SETTLE_TIME: Duration = Duration::from_millis(100);
+
+/// Reports changes to a set of files and everything under a set of directories.
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    files: Vec<PathBuf>,
+    dirs: Vec<PathBuf>,
+}
+
+/// Absolute form of `path`, which may not exist yet as long as its directory does.
+fn absolute(path: &Path) -> Result<PathBuf> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let name = path
+        .file_name()
+        .with_context(|| format!("Cannot watch {}", path.display()))?;
+    let dir = fs::canonicalize(dir).with_context(|| format!("Cannot watch {}", dir.display()))?;
+
+    Ok(dir.join(name))
+}
+
+/// Whether `event` can mean the contents changed, as opposed to e.g. someone reading the file.
+fn is_change(event: &Event) -> bool {
+    match event.kind {
+        EventKind::Access(AccessKind::Close(AccessMode::Write)) => true,
+        EventKind::Access(_) => false,
+        _ => true,
+    }
+}
+
+impl FileWatcher {
+    /// Watches `files` and everything under `dirs`.
+    ///
+    /// The directories of `files` are watched rather than the files themselves, so files that
+    /// are replaced instead of written to, as many editors do, are still followed.
+    pub fn new(files: &[PathBuf], dirs: &[PathBuf]) -> Result<Self> {
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+
+        let files = files
+            .iter()
+            .map(|path| absolute(path))
+            .collect::<Result<Vec<_>>>()?;
+        let dirs = dirs
+            .iter()
+            .map(|dir| {
+                fs::canonicalize(dir).with_context(|| format!("Cannot watch {}", dir.display()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for file in &files {
+            let dir = file.parent().expect("absolute paths have a parent");
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        }
+
+        for dir in &dirs {
+            watcher.watch(dir, RecursiveMode::Recursive)?;
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            events,
+            files,
+            dirs,
+        })
+    }
+
+    fn is_watched(&self, path: &Path) -> bool {
+        self.files.iter().any(|file| file == path)
+            || self.dirs.iter().any(|dir| path.starts_with(dir))
+    }
+
+    /// Changed watched paths in `event`.
+    fn changed(&self, event: notify::Result<Event>) -> Result<Vec<PathBuf>> {
+        let event = event?;
+
+        if !is_change(&event) {
+            return Ok(vec![]);
+        }
+
+        Ok(event
+            .paths
+            .into_iter()
+            .filter(|path| self.is_watched(path))
+            .collect())
+    }
+
+    /// Blocks until a watched path changes, returning every path that changed meanwhile.
+    pub fn wait(&self) -> Result<Vec<PathBuf>> {
+        loop {
+            if let Some(changed) = self.wait_timeout(Duration::from_secs(3600))? {
+                return Ok(changed);
+            }
+        }
+    }
+
+    /// Like [`FileWatcher::wait`], giving up with `None` after `timeout`.
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<Option<Vec<PathBuf>>> {
+        let deadline = Instant::now() + timeout;
+        let mut changed = vec![];
+
+        while changed.is_empty() {
+            let left = deadline.saturating_duration_since(Instant::now());
+
+            match self.events.recv_timeout(left) {
+                Ok(event) => changed = self.changed(event)?,
+                Err(RecvTimeoutError::Timeout) => return Ok(None),
+                Err(RecvTimeoutError::Disconnected) => anyhow::bail!("File watcher stopped"),
+            }
+        }
+
+        while let Ok(event) = self.events.recv_timeout(SETTLE_TIME) {
+            changed.extend(self.changed(event)?);
+        }
+
+        changed.sort();
+        changed.dedup();
+
+        Ok(Some(changed))
+    }
+}
+
+/// Timings and answers of one run of a day on one input.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunResult {
+    pub parse_time: Duration,
+    /// Answer and solve time by part number.
+    pub answers: BTreeMap<u8, (String, Duration)>,
+}
+
+/// `current` along with how it changed since `previous`, e.g. `1.2ms (+300.0µs)`.
+pub fn time_change(previous: Option<Duration>, current: Duration) -> String {
+    match previous {
+        None => format!("{current:.1?}"),
+        Some(previous) if current >= previous => {
+            format!("{current:.1?} (+{:.1?})", current - previous)
+        }
+        Some(previous) => format!("{current:.1?} (-{:.1?})", previous - current),
+    }
+}
+
+/// How `current` differs from the `previous` answer, `None` on the first run.
+pub fn answer_change(previous: Option<&str>, current: &str) -> Option<String> {
+    let previous = previous?;
+
+    let change = if previous == current {
+        "unchanged".to_owned()
+    } else if previous.contains('\n') || current.contains('\n') {
+        "changed".to_owned()
+    } else {
+        format!("was {previous}")
+    };
+
+    Some(change)
+}
@@ -0,0 +1,71 @@
+use std::hint::black_box;
+
+use aoc2022_rust::alloc_stats::{self, format_bytes, AllocStats};
+
+// with the feature the library installs it already
+#[cfg(not(feature = "alloc-stats"))]
+#[global_allocator]
+static GLOBAL: alloc_stats::CountingAllocator = alloc_stats::CountingAllocator;
+
+// other tests run on other threads meanwhile, so counts are only ever bounded from below
+#[test]
+fn measure_counts_allocations() {
+    let (_, stats) = alloc_stats::measure(|| {
+        for n in 1..=10 {
+            black_box(vec![0u8; n * 1000]);
+        }
+    });
+
+    assert!(stats.allocations >= 10);
+    assert!(stats.bytes >= 55_000);
+    assert!(stats.peak >= 10_000);
+}
+
+#[test]
+fn peak_counts_memory_held_at_once() {
+    let (kept, stats) = alloc_stats::measure(|| {
+        let kept = black_box(vec![0u8; 200_000]);
+        black_box(vec![0u8; 100_000]);
+
+        kept
+    });
+
+    assert_eq!(kept.len(), 200_000);
+    assert!(stats.peak >= 300_000);
+    assert!(stats.bytes >= 300_000);
+}
+
+#[test]
+fn growing_a_vec_counts_reallocations() {
+    let (_, stats) = alloc_stats::measure(|| {
+        let mut v = Vec::new();
+
+        for i in 0..100_000u32 {
+            v.push(black_box(i));
+        }
+
+        v.len()
+    });
+
+    assert!(stats.allocations > 1);
+}
+
+#[test]
+fn stats_display() {
+    let stats = AllocStats {
+        allocations: 3,
+        bytes: 1536,
+        peak: 512,
+    };
+
+    assert_eq!(stats.to_string(), "1.5 KiB in 3 allocations, peak 512 B");
+}
+
+#[test]
+fn bytes_in_binary_units() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1024), "1.0 KiB");
+    assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use aoc2022_rust::bench::{self, BenchConfig, Stats};
+
+fn micros(samples: &[u64]) -> Vec<Duration> {
+    samples.iter().copied().map(Duration::from_micros).collect()
+}
+
+#[test]
+fn stats_of_no_samples() {
+    assert_eq!(Stats::from_samples(&[]), None);
+}
+
+#[test]
+fn stats_of_odd_sample_count() {
+    let stats = Stats::from_samples(&micros(&[5, 1, 3])).unwrap();
+
+    assert_eq!(stats.runs, 3);
+    assert_eq!(stats.min, Duration::from_micros(1));
+    assert_eq!(stats.median, Duration::from_micros(3));
+    assert_eq!(stats.mean, Duration::from_micros(3));
+    assert_eq!(stats.stddev, Duration::from_micros(2));
+}
+
+#[test]
+fn stats_of_even_sample_count() {
+    let stats = Stats::from_samples(&micros(&[4, 2, 8, 6])).unwrap();
+
+    assert_eq!(stats.median, Duration::from_micros(5));
+    assert_eq!(stats.mean, Duration::from_micros(5));
+}
+
+#[test]
+fn single_sample_has_no_deviation() {
+    let stats = Stats::from_samples(&micros(&[7])).unwrap();
+
+    assert_eq!(stats.stddev, Duration::ZERO);
+}
+
+#[test]
+fn measure_runs_warmup_and_fixed_runs() {
+    let config = BenchConfig {
+        warmup: 2,
+        runs: Some(5),
+        ..BenchConfig::default()
+    };
+    let mut calls = 0;
+
+    let stats = bench::measure(&config, || calls += 1);
+
+    assert_eq!(stats.runs, 5);
+    assert_eq!(calls, 7);
+}
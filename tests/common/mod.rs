@@ -0,0 +1,37 @@
+//! Fixtures shared by the test crates.
+
+// not every test crate including this module uses every fixture
+#![allow(dead_code)]
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// Empty directory of a test's own, removed with everything in it once dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// `name` keeps the directories of tests running at the same time apart.
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("aoc2022-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        // file watchers report resolved paths, the temp dir may be behind a symlink
+        Self(dir.canonicalize().unwrap())
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,264 @@
+//! Fuzzing every parser: whatever the input, parsing returns an error instead of panicking, and
+//! so does solving whatever parses.
+//!
+//! Inputs are either arbitrary text or valid inputs from the generators with random edits. Run
+//! longer sessions with e.g. `PROPTEST_CASES=100000 cargo test --release --test fuzz`.
+
+mod generators;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use aoc2022_rust::days::{self, d05, d11, d13, d16};
+use aoc2022_rust::Part;
+
+/// Text made of the characters puzzle inputs are made of, so it gets past the first checks.
+const PUZZLE_TEXT: &str = "[\\[\\],0-9a-zA-Z :=+*>\n-]{0,200}";
+
+/// A random change to an input.
+#[derive(Clone, Debug)]
+enum Edit {
+    Delete(Index),
+    Insert(Index, char),
+    Replace(Index, char),
+    /// Copies a range of characters over to another place, e.g. duplicating a line.
+    Splice(Index, Index, Index),
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    let c = prop_oneof![
+        prop::sample::select(vec!['\n', ' ', ',', '[', ']', '-', '0', '9', 'a', 'S', 'E']),
+        any::<char>(),
+    ];
+
+    prop_oneof![
+        any::<Index>().prop_map(Edit::Delete),
+        (any::<Index>(), c.clone()).prop_map(|(i, c)| Edit::Insert(i, c)),
+        (any::<Index>(), c).prop_map(|(i, c)| Edit::Replace(i, c)),
+        (any::<Index>(), any::<Index>(), any::<Index>())
+            .prop_map(|(from, len, to)| Edit::Splice(from, len, to)),
+    ]
+}
+
+/// Valid inputs from `valid` with a few random edits.
+fn mangled(valid: impl Strategy<Value = String>) -> impl Strategy<Value = String> {
+    (valid, vec(edit(), 1..4)).prop_map(|(input, edits)| {
+        let mut chars: Vec<char> = input.chars().collect();
+
+        for edit in edits {
+            let len = chars.len();
+            match edit {
+                Edit::Delete(i) if len > 0 => {
+                    chars.remove(i.index(len));
+                }
+                Edit::Insert(i, c) => chars.insert(i.index(len + 1), c),
+                Edit::Replace(i, c) if len > 0 => chars[i.index(len)] = c,
+                Edit::Splice(from, n, to) if len > 0 => {
+                    let from = from.index(len);
+                    let n = n.index((len - from).min(80) + 1);
+                    let copied: Vec<_> = chars[from..from + n].to_vec();
+                    let to = to.index(len + 1);
+                    chars.splice(to..to, copied);
+                }
+                _ => (),
+            }
+        }
+
+        chars.into_iter().collect()
+    })
+}
+
+/// Any registered day.
+fn days_solved() -> impl Strategy<Value = u8> {
+    prop::sample::select(
+        days::ALL
+            .iter()
+            .map(|solution| solution.day())
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Deepest rock in a day 14 scan, as far as it parses.
+fn cave_depth(scan: &str) -> usize {
+    scan.lines()
+        .flat_map(|line| line.split(" -> "))
+        .filter_map(|corner| corner.split_once(',')?.1.parse().ok())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Parameters keeping the slower days quick on the small inputs fuzzed here.
+fn quick_params(day: u8) -> Vec<(String, String)> {
+    let pairs: &[(&str, &str)] = match day {
+        15 => &[("row", "0"), ("max", "60")],
+        19 => &[("minutes1", "12"), ("minutes2", "14")],
+        _ => &[],
+    };
+
+    pairs
+        .iter()
+        .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+        .collect()
+}
+
+/// Parses `input` as the input of `day` and solves both parts if it parses, any panic fails the
+/// test.
+fn parse_and_solve(day: u8, input: &str) {
+    let solution = days::get(day)
+        .unwrap()
+        .with_params(&quick_params(day))
+        .unwrap();
+
+    if let Ok(parsed) = solution.parse_dyn(input) {
+        for part in Part::BOTH {
+            let _ = solution.solve_dyn(parsed.as_ref(), part);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_text(day in days_solved(), input in PUZZLE_TEXT) {
+        parse_and_solve(day, &input);
+    }
+
+    #[test]
+    fn arbitrary_strings(day in days_solved(), input in any::<String>()) {
+        parse_and_solve(day, &input);
+    }
+
+    #[test]
+    fn d05_stacks(drawing in "[\\[\\] A-Z1-9\n]{0,100}") {
+        let _ = d05::parse_stacks(&drawing);
+    }
+
+    #[test]
+    fn d05_stack_numbers(numbers in "( {1,3}[0-9]{1,20}){1,5} ?") {
+        let _ = d05::parse_stacks(&format!("[A] [B]\n{numbers}"));
+    }
+
+    #[test]
+    fn d11_monkey(desc in mangled(generators::monkeys())) {
+        let _ = d11::parse_monkey(&desc);
+    }
+
+    #[test]
+    fn d11_operation(line in "  Operation: new = (old|[0-9]{1,25}) [-+*/ ]{0,2} (old|[0-9]{0,25}|)") {
+        let _ = d11::parse_operation(&line);
+    }
+
+    #[test]
+    fn d13_packet(line in "[\\[\\],0-9 ]{0,60}") {
+        let _ = d13::parse_packet(&line);
+    }
+
+    #[test]
+    fn d01_mangled(input in mangled(generators::calories())) {
+        parse_and_solve(1, &input);
+    }
+
+    #[test]
+    fn d02_mangled(input in mangled(generators::strategy_guide())) {
+        parse_and_solve(2, &input);
+    }
+
+    #[test]
+    fn d03_mangled(input in mangled(generators::rucksacks())) {
+        parse_and_solve(3, &input);
+    }
+
+    #[test]
+    fn d04_mangled(input in mangled(generators::section_pairs())) {
+        parse_and_solve(4, &input);
+    }
+
+    #[test]
+    fn d05_mangled(input in mangled(generators::crate_stacks())) {
+        parse_and_solve(5, &input);
+    }
+
+    #[test]
+    fn d06_mangled(input in mangled(generators::datastream())) {
+        parse_and_solve(6, &input);
+    }
+
+    #[test]
+    fn d07_mangled(input in mangled(generators::shell_transcript())) {
+        parse_and_solve(7, &input);
+    }
+
+    #[test]
+    fn d08_mangled(input in mangled(generators::tree_grid())) {
+        parse_and_solve(8, &input);
+    }
+
+    #[test]
+    fn d09_mangled(input in mangled(generators::rope_moves())) {
+        parse_and_solve(9, &input);
+    }
+
+    #[test]
+    fn d10_mangled(input in mangled(generators::program())) {
+        parse_and_solve(10, &input);
+    }
+
+    #[test]
+    fn d11_mangled(input in mangled(generators::monkeys())) {
+        parse_and_solve(11, &input);
+    }
+
+    #[test]
+    fn d12_mangled(input in mangled(generators::heightmap())) {
+        parse_and_solve(12, &input);
+    }
+
+    #[test]
+    fn d13_mangled(input in mangled(generators::packet_pairs())) {
+        parse_and_solve(13, &input);
+    }
+
+    // sand takes minutes to fill deep caves in debug builds
+    #[test]
+    fn d14_mangled(
+        input in mangled(generators::rock_paths())
+            .prop_filter("deep cave", |scan| cave_depth(scan) <= 100)
+    ) {
+        parse_and_solve(14, &input);
+    }
+
+    #[test]
+    fn d15_mangled(input in mangled(generators::sensor_reports(0))) {
+        parse_and_solve(15, &input);
+    }
+
+    #[test]
+    fn d16_mangled(input in mangled(generators::valve_network())) {
+        parse_and_solve(16, &input);
+    }
+
+    #[test]
+    fn d17_mangled(input in mangled(generators::jet_pattern())) {
+        parse_and_solve(17, &input);
+    }
+
+    #[test]
+    fn d18_mangled(input in mangled(generators::lava_cubes())) {
+        parse_and_solve(18, &input);
+    }
+
+    #[test]
+    fn d19_mangled(input in mangled(generators::blueprints())) {
+        parse_and_solve(19, &input);
+    }
+
+    #[test]
+    fn d20_mangled(input in mangled(generators::encrypted_file())) {
+        parse_and_solve(20, &input);
+    }
+
+    #[test]
+    fn d16_valve(line in "Valve [A-Z ]{0,3} has flow rate=[0-9]{0,8}; tunnels? leads? to valves? [A-Z, ]{0,12}") {
+        let _ = d16::parse_valve(&line);
+    }
+}
@@ -0,0 +1,473 @@
+//! Strategies producing random valid puzzle inputs, one per day.
+//!
+//! Every input is what the puzzle could have handed out: parsers accept it and solutions can
+//! answer it without panicking. Inputs end with a newline, like the downloaded ones.
+
+// not every test crate including this module uses every strategy
+#![allow(dead_code)]
+
+use std::iter;
+
+use itertools::Itertools;
+use proptest::collection::{hash_set, vec};
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+/// Lines joined into a newline-terminated input.
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// Day 1: calorie counts of at least three elves, one group per elf.
+pub fn calories() -> impl Strategy<Value = String> {
+    vec(vec(1..100_000usize, 1..8), 3..20).prop_map(|elves| {
+        elves
+            .iter()
+            .map(|elf| lines(elf.iter().map(ToString::to_string)))
+            .join("\n")
+    })
+}
+
+/// Day 2: strategy guide rounds like `A Y`.
+pub fn strategy_guide() -> impl Strategy<Value = String> {
+    vec((0..3u8, 0..3u8), 1..100).prop_map(|rounds| {
+        lines(
+            rounds
+                .iter()
+                .map(|(a, b)| format!("{} {}", (b'A' + a) as char, (b'X' + b) as char)),
+        )
+    })
+}
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Items of one rucksack drawn from its private pool: extra items of either compartment, and
+/// which compartment holds the badge.
+type Packing = (Vec<Index>, Vec<Index>, bool);
+
+/// Day 3: rucksacks in groups of three.
+///
+/// Every rucksack has a single item in both compartments, and every group a single badge item
+/// carried by all three elves.
+pub fn rucksacks() -> impl Strategy<Value = String> {
+    let packing = (
+        vec(any::<Index>(), 0..8),
+        vec(any::<Index>(), 0..8),
+        any::<bool>(),
+    );
+    let items = Just(ITEMS.chars().collect_vec()).prop_shuffle();
+    let group = (items, prop::array::uniform3(packing));
+
+    vec(group, 1..10).prop_map(|groups| {
+        let sacks = groups.iter().flat_map(|(items, packings)| {
+            let badge = items[0];
+
+            packings.iter().enumerate().map(move |(i, packing)| {
+                let pool = &items[1 + 17 * i..1 + 17 * (i + 1)];
+                rucksack(badge, pool, packing)
+            })
+        });
+
+        lines(sacks)
+    })
+}
+
+/// A rucksack with items from `pool`: its first item goes in both compartments, the next 8 only
+/// in the first and the last 8 only in the second.
+fn rucksack(badge: char, pool: &[char], (first, second, badge_first): &Packing) -> String {
+    let shared = pool[0];
+    let mut first: Vec<_> = iter::once(shared)
+        .chain(first.iter().map(|i| *i.get(&pool[1..9])))
+        .collect();
+    let mut second: Vec<_> = iter::once(shared)
+        .chain(second.iter().map(|i| *i.get(&pool[9..17])))
+        .collect();
+
+    if *badge_first {
+        first.push(badge);
+    } else {
+        second.push(badge);
+    }
+
+    let len = first.len().max(second.len());
+    first.resize(len, shared);
+    second.resize(len, shared);
+
+    first.into_iter().chain(second).collect()
+}
+
+/// Day 4: pairs of section ranges like `2-4,6-8`.
+pub fn section_pairs() -> impl Strategy<Value = String> {
+    let range = (1..100u32, 0..50u32).prop_map(|(start, len)| format!("{start}-{}", start + len));
+
+    vec((range.clone(), range), 1..100)
+        .prop_map(|pairs| lines(pairs.into_iter().map(|(a, b)| format!("{a},{b}"))))
+}
+
+/// Day 5: a drawing of crate stacks followed by moves.
+///
+/// Every stack starts with a crate and no move takes the last one, so every stack has a top
+/// crate at the end.
+pub fn crate_stacks() -> impl Strategy<Value = String> {
+    let crates = prop::char::range('A', 'Z');
+    let first = vec(crates.clone(), 2..8);
+    let rest = vec(vec(crates, 1..8), 1..9);
+    let moves = vec((any::<Index>(), any::<Index>(), any::<Index>()), 1..30);
+
+    (first, rest, moves).prop_map(|(first, rest, moves)| {
+        let stacks: Vec<_> = iter::once(first).chain(rest).collect();
+        let drawing = draw_stacks(&stacks);
+        let moves = plan_moves(stacks, &moves);
+
+        format!("{drawing}\n{}", lines(moves))
+    })
+}
+
+fn draw_stacks(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let rows = (0..height).rev().map(|level| {
+        stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_owned(),
+            })
+            .join(" ")
+    });
+    let numbers = (1..=stacks.len()).map(|i| format!(" {i} ")).join(" ");
+
+    lines(rows.chain([numbers]))
+}
+
+/// Turns random picks into valid moves, applying them to `stacks` on the way.
+fn plan_moves(mut stacks: Vec<Vec<char>>, picks: &[(Index, Index, Index)]) -> Vec<String> {
+    let mut moves = vec![];
+
+    for (from, to, n) in picks {
+        let sources = (0..stacks.len())
+            .filter(|&i| stacks[i].len() > 1)
+            .collect_vec();
+        if sources.is_empty() {
+            continue;
+        }
+
+        let from = *from.get(&sources);
+        let targets = (0..stacks.len()).filter(|&i| i != from).collect_vec();
+        let to = *to.get(&targets);
+        let n = 1 + n.index(stacks[from].len() - 1);
+
+        let split = stacks[from].len() - n;
+        let moved = stacks[from].split_off(split);
+        stacks[to].extend(moved);
+
+        moves.push(format!("move {n} from {} to {}", from + 1, to + 1));
+    }
+
+    moves
+}
+
+/// Day 6: lowercase datastream with a start-of-message marker somewhere in it.
+pub fn datastream() -> impl Strategy<Value = String> {
+    let noise = || vec(prop::char::range('a', 'z'), 0..100);
+    let marker = Just(('a'..='z').collect_vec()).prop_shuffle();
+
+    (noise(), marker, noise()).prop_map(|(before, marker, after)| {
+        let stream: String = before
+            .into_iter()
+            .chain(marker.into_iter().take(14))
+            .chain(after)
+            .collect();
+
+        lines([stream])
+    })
+}
+
+/// A directory tree for day 7: files with their sizes and non-empty directories.
+#[derive(Clone, Debug)]
+enum Node {
+    File(usize),
+    Dir(Vec<Node>),
+}
+
+/// Day 7: a shell transcript exploring every directory of a disk that is over 40M full, so the
+/// update needs space freed.
+pub fn shell_transcript() -> impl Strategy<Value = String> {
+    let file = (1..300_000usize).prop_map(Node::File);
+    let node = file.prop_recursive(4, 64, 6, |inner| vec(inner, 1..6).prop_map(Node::Dir));
+    let filler = 40_000_001..50_000_000usize;
+
+    (vec(node, 1..6), filler).prop_map(|(root, filler)| {
+        let mut transcript = vec!["$ cd /".to_owned()];
+        let root: Vec<_> = iter::once(Node::File(filler)).chain(root).collect();
+        explore(&root, &mut transcript);
+
+        lines(transcript)
+    })
+}
+
+fn explore(entries: &[Node], transcript: &mut Vec<String>) {
+    transcript.push("$ ls".to_owned());
+
+    for (i, entry) in entries.iter().enumerate() {
+        transcript.push(match entry {
+            Node::File(size) => format!("{size} f{i}.txt"),
+            Node::Dir(_) => format!("dir d{i}"),
+        });
+    }
+
+    for (i, entry) in entries.iter().enumerate() {
+        if let Node::Dir(children) = entry {
+            transcript.push(format!("$ cd d{i}"));
+            explore(children, transcript);
+            transcript.push("$ cd ..".to_owned());
+        }
+    }
+}
+
+/// Rows of single digits for `width` x `height` grids.
+fn digit_grid(width: usize, height: usize) -> impl Strategy<Value = String> {
+    vec(vec(0..10u8, width), height).prop_map(|rows| {
+        lines(
+            rows.iter()
+                .map(|row| row.iter().map(u8::to_string).collect()),
+        )
+    })
+}
+
+/// Day 8: a grid of tree heights.
+pub fn tree_grid() -> impl Strategy<Value = String> {
+    (2..12usize, 2..12usize).prop_flat_map(|(width, height)| digit_grid(width, height))
+}
+
+/// Day 9: rope moves like `R 4`.
+pub fn rope_moves() -> impl Strategy<Value = String> {
+    let step = (prop::sample::select(vec!['U', 'D', 'L', 'R']), 1..20usize);
+
+    vec(step, 1..100).prop_map(|steps| lines(steps.iter().map(|(dir, n)| format!("{dir} {n}"))))
+}
+
+/// Day 10: a program running for at least the 240 cycles the CRT draws.
+pub fn program() -> impl Strategy<Value = String> {
+    let instruction = prop_oneof![
+        Just("noop".to_owned()),
+        (-20..=20i64).prop_map(|v| format!("addx {v}")),
+    ];
+
+    vec(instruction, 240..300).prop_map(lines)
+}
+
+const TEST_DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Day 11: monkey notes, every monkey throwing to two others.
+pub fn monkeys() -> impl Strategy<Value = String> {
+    let operation = prop_oneof![
+        (1..10u64).prop_map(|v| format!("old + {v}")),
+        (2..20u64).prop_map(|v| format!("old * {v}")),
+        Just("old * old".to_owned()),
+    ];
+    let monkey = (
+        vec(1..100u64, 1..6),
+        operation,
+        prop::sample::select(TEST_DIVISORS.to_vec()),
+        any::<Index>(),
+        any::<Index>(),
+    );
+
+    vec(monkey, 2..8).prop_map(|monkeys| {
+        let n = monkeys.len();
+
+        monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, (items, operation, test, if_true, if_false))| {
+                let others = (0..n).filter(|&j| j != i).collect_vec();
+
+                format!(
+                    "Monkey {i}:\n  Starting items: {}\n  Operation: new = {operation}\n  \
+                     Test: divisible by {test}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}\n",
+                    items.iter().join(", "),
+                    if_true.get(&others),
+                    if_false.get(&others),
+                )
+            })
+            .join("\n")
+    })
+}
+
+/// Day 12: a heightmap with a climbable path from `S` to `E`.
+///
+/// Elevation never drops along a path snaking through every row, and rises by at most one per
+/// step, from `a` at `S` in the top left corner to `z` at `E`.
+pub fn heightmap() -> impl Strategy<Value = String> {
+    (2..12usize, 3..12usize)
+        .prop_filter("too small to climb from a to z", |(w, h)| w * h >= 26)
+        .prop_flat_map(|(width, height)| {
+            let climbs = Just((0..width * height - 1).collect_vec()).prop_shuffle();
+            (Just(width), Just(height), climbs)
+        })
+        .prop_map(|(width, height, steps)| {
+            let len = width * height;
+            let climbs: Vec<_> = steps.into_iter().take(25).collect();
+
+            let mut elevation = vec![0u8; len];
+            for k in 1..len {
+                elevation[k] = elevation[k - 1] + u8::from(climbs.contains(&(k - 1)));
+            }
+
+            let mut rows = vec![vec![' '; width]; height];
+            for (k, &e) in elevation.iter().enumerate() {
+                let (row, col) = (k / width, k % width);
+                let col = if row % 2 == 0 { col } else { width - 1 - col };
+
+                rows[row][col] = match k {
+                    0 => 'S',
+                    k if k == len - 1 => 'E',
+                    _ => (b'a' + e) as char,
+                };
+            }
+
+            lines(rows.into_iter().map(String::from_iter))
+        })
+}
+
+/// A packet for day 13: a list of integers and nested lists.
+fn packet() -> impl Strategy<Value = String> {
+    let list = |items: Vec<String>| format!("[{}]", items.join(","));
+    let value = (0..=10u16)
+        .prop_map(|n| n.to_string())
+        .prop_recursive(4, 32, 5, move |inner| vec(inner, 0..5).prop_map(list));
+
+    vec(value, 0..5).prop_map(list)
+}
+
+/// Day 13: pairs of packets separated by blank lines.
+pub fn packet_pairs() -> impl Strategy<Value = String> {
+    vec((packet(), packet()), 1..20)
+        .prop_map(|pairs| pairs.into_iter().map(|(a, b)| lines([a, b])).join("\n"))
+}
+
+/// Day 14: horizontal and vertical rock paths below the sand source, reaching past it on the
+/// right like every real scan does.
+///
+/// Rocks lie too deep for sand piled on them to reach the source, so some of it always falls
+/// into the abyss.
+pub fn rock_paths() -> impl Strategy<Value = String> {
+    let segment = (any::<bool>(), -6..=6i32);
+    let path = ((480..=520i32, 22..=40i32), vec(segment, 1..5)).prop_map(|((x, y), segments)| {
+        segments
+            .into_iter()
+            .scan((x, y), |(x, y), (horizontal, d)| {
+                if horizontal {
+                    *x = (*x + d).clamp(480, 520);
+                } else {
+                    *y = (*y + d).clamp(22, 40);
+                }
+                Some((*x, *y))
+            })
+            .fold(vec![(x, y)], |mut points, point| {
+                points.push(point);
+                points
+            })
+    });
+
+    vec(path, 1..8)
+        .prop_filter("the scan has to reach past the source", |paths| {
+            paths.iter().flatten().any(|&(x, _)| x >= 500)
+        })
+        .prop_map(|paths| {
+            lines(
+                paths
+                    .iter()
+                    .map(|points| points.iter().map(|(x, y)| format!("{x},{y}")).join(" -> ")),
+            )
+        })
+}
+
+/// Day 15: sensor reports within 30 of the scanned row, around the origin.
+pub fn sensor_reports(row: isize) -> impl Strategy<Value = String> {
+    let position = (-30..=30isize, -30..=30isize);
+
+    vec((position.clone(), position), 1..8).prop_map(move |reports| {
+        lines(reports.iter().map(|((sx, sy), (bx, by))| {
+            format!(
+                "Sensor at x={sx}, y={}: closest beacon is at x={bx}, y={}",
+                row + sy,
+                row + by
+            )
+        }))
+    })
+}
+
+/// Day 16: up to a dozen valves named `AA`, `BB`, ..., each with at least one tunnel.
+pub fn valve_network() -> impl Strategy<Value = String> {
+    (1..=12usize)
+        .prop_flat_map(|n| vec((0..25u16, vec(0..n, 1..4)), n))
+        .prop_map(|valves| {
+            let name = |i: usize| ((b'A' + i as u8) as char).to_string().repeat(2);
+
+            lines(valves.iter().enumerate().map(|(i, (rate, tunnels))| {
+                let tunnels = tunnels.iter().map(|&j| name(j)).join(", ");
+                let tunnels = if tunnels.contains(',') {
+                    format!("tunnels lead to valves {tunnels}")
+                } else {
+                    format!("tunnel leads to valve {tunnels}")
+                };
+
+                format!("Valve {} has flow rate={rate}; {tunnels}", name(i))
+            }))
+        })
+}
+
+/// Day 17: a jet pattern of `<` and `>`.
+pub fn jet_pattern() -> impl Strategy<Value = String> {
+    "[<>]{1,60}".prop_map(|jets| jets + "\n")
+}
+
+/// Day 18: distinct cubes in a small box, so some of them touch and enclose air.
+pub fn lava_cubes() -> impl Strategy<Value = String> {
+    hash_set((0..8i32, 0..8i32, 0..8i32), 1..120).prop_map(|cubes| {
+        lines(
+            cubes
+                .into_iter()
+                .sorted()
+                .map(|(x, y, z)| format!("{x},{y},{z}")),
+        )
+    })
+}
+
+/// Day 19: blueprints with costs in the ranges real ones use, numbered from 1.
+pub fn blueprints() -> impl Strategy<Value = String> {
+    let costs = (
+        2..=4u32,
+        2..=4u32,
+        (2..=4u32, 5..=20u32),
+        (2..=4u32, 5..=20u32),
+    );
+
+    vec(costs, 1..4).prop_map(|blueprints| {
+        lines(blueprints.iter().enumerate().map(
+            |(i, (ore, clay, (obsidian_ore, obsidian_clay), (geode_ore, geode_obsidian)))| {
+                format!(
+                    "Blueprint {}: Each ore robot costs {ore} ore. \
+                     Each clay robot costs {clay} ore. \
+                     Each obsidian robot costs {obsidian_ore} ore and {obsidian_clay} clay. \
+                     Each geode robot costs {geode_ore} ore and {geode_obsidian} obsidian.",
+                    i + 1
+                )
+            },
+        ))
+    })
+}
+
+/// Day 20: small numbers with duplicates and a single 0 somewhere.
+pub fn encrypted_file() -> impl Strategy<Value = String> {
+    let n = (-30..=30i16).prop_filter("only one 0", |&n| n != 0);
+
+    (vec(n, 0..60), any::<Index>()).prop_map(|(mut numbers, zero)| {
+        numbers.insert(zero.index(numbers.len() + 1), 0);
+
+        lines(numbers.iter().map(ToString::to_string))
+    })
+}
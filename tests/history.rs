@@ -0,0 +1,140 @@
+mod common;
+
+use aoc2022_rust::history::{self, Entry};
+
+use common::TempDir;
+
+fn entry(run: u64, label: Option<&str>, day: u8, step: &str, median_ns: u64) -> Entry {
+    Entry {
+        run,
+        commit: Some(format!("commit{run}")),
+        label: label.map(str::to_owned),
+        day,
+        input: format!("input/day{day:02}.txt"),
+        params: vec![],
+        step: step.to_owned(),
+        median_ns,
+    }
+}
+
+fn sample_history() -> Vec<Entry> {
+    vec![
+        entry(1, Some("base"), 14, "part1", 1000),
+        entry(1, Some("base"), 14, "part2", 2000),
+        entry(2, None, 14, "part1", 1050),
+        entry(2, None, 14, "part2", 2100),
+        entry(3, None, 14, "part1", 1300),
+        entry(3, None, 14, "part2", 2000),
+        entry(3, None, 15, "part1", 500),
+    ]
+}
+
+#[test]
+fn entries_are_grouped_by_run() {
+    let entries = sample_history();
+    let runs = history::runs(&entries);
+
+    let ids: Vec<_> = runs.iter().map(|run| run.id).collect();
+    assert_eq!(ids, [1, 2, 3]);
+    assert_eq!(runs[0].label, Some("base"));
+    assert_eq!(runs[2].entries.len(), 3);
+}
+
+#[test]
+fn latest_run_is_compared_with_the_previous_one_by_default() {
+    let entries = sample_history();
+    let runs = history::runs(&entries);
+
+    let (latest, base) = history::select(&runs, None).unwrap();
+
+    assert_eq!((latest.id, base.id), (3, 2));
+}
+
+#[test]
+fn latest_run_is_compared_with_a_labelled_baseline() {
+    let entries = sample_history();
+    let runs = history::runs(&entries);
+
+    let (latest, base) = history::select(&runs, Some("base")).unwrap();
+    assert_eq!((latest.id, base.id), (3, 1));
+
+    assert!(history::select(&runs, Some("missing")).is_none());
+}
+
+#[test]
+fn a_single_run_has_nothing_to_compare_with() {
+    let entries = vec![entry(1, None, 1, "part1", 10)];
+    let runs = history::runs(&entries);
+
+    assert!(history::select(&runs, None).is_none());
+}
+
+#[test]
+fn slowdowns_above_the_threshold_are_regressions() {
+    let entries = sample_history();
+    let runs = history::runs(&entries);
+    let (latest, base) = history::select(&runs, Some("base")).unwrap();
+
+    let changes = history::compare(latest, base);
+    let regressed: Vec<_> = changes
+        .iter()
+        .filter(|change| change.is_regression(10.0))
+        .map(|change| (change.day, change.step.as_str()))
+        .collect();
+
+    assert_eq!(changes.len(), 2, "day 15 is missing from the baseline");
+    assert_eq!(regressed, [(14, "part1")]);
+}
+
+#[test]
+fn only_steps_on_the_same_input_and_params_are_compared() {
+    let mut entries = vec![
+        entry(1, None, 15, "part1", 1000),
+        entry(1, None, 16, "part1", 1000),
+        entry(2, None, 15, "part1", 5000),
+        entry(2, None, 16, "part1", 5000),
+    ];
+    entries[2].params = vec![("row".to_owned(), "10".to_owned())];
+    entries[3].input = "examples/day16.txt".to_owned();
+    let runs = history::runs(&entries);
+    let (latest, base) = history::select(&runs, None).unwrap();
+
+    assert_eq!(history::compare(latest, base), []);
+
+    entries[0].params = entries[2].params.clone();
+    let runs = history::runs(&entries);
+    let (latest, base) = history::select(&runs, None).unwrap();
+    let days: Vec<_> = history::compare(latest, base)
+        .iter()
+        .map(|change| change.day)
+        .collect();
+
+    assert_eq!(days, [15]);
+}
+
+#[test]
+fn entries_without_an_input_or_params_still_load() {
+    let dir = TempDir::new("old-history");
+    let path = dir.join("history.jsonl");
+    let line = r#"{"run":1,"commit":null,"label":null,"day":1,"step":"part1","median_ns":10}"#;
+    std::fs::write(&path, format!("{line}\n")).unwrap();
+    let loaded = history::load(&path).unwrap();
+
+    assert_eq!(loaded[0].input, "");
+    assert_eq!(loaded[0].params, []);
+}
+
+#[test]
+fn history_survives_a_round_trip() {
+    let dir = TempDir::new("history");
+    let path = dir.join("history.jsonl");
+    let entries = sample_history();
+
+    assert_eq!(history::load(&path).unwrap(), []);
+
+    history::append(&path, &entries[..2]).unwrap();
+    history::append(&path, &entries[2..]).unwrap();
+    let loaded = history::load(&path).unwrap();
+
+    assert_eq!(loaded, entries);
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use std::cell::Cell;
+use std::fs;
+use std::rc::Rc;
+use std::thread::{self, JoinHandle};
+
+use anyhow::Result;
+use tiny_http::{Response, Server};
+
+use aoc2022_rust::client::Client;
+use aoc2022_rust::input::{Fetch, HttpFetcher, InputStore};
+
+use common::TempDir;
+
+/// A request as seen by the stub server: URL, `Cookie` and `User-Agent` headers.
+type SeenRequest = (String, Option<String>, Option<String>);
+
+/// Serves one response per `(status, body)` pair, then returns the requests it got.
+fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<SeenRequest>>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let port = server.server_addr().to_ip().unwrap().port();
+
+    let handle = thread::spawn(move || {
+        let mut seen = vec![];
+
+        for (status, body) in responses {
+            let request = server.recv().unwrap();
+            let header = |name: &'static str| {
+                request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv(name))
+                    .map(|h| h.value.to_string())
+            };
+
+            seen.push((
+                request.url().to_owned(),
+                header("Cookie"),
+                header("User-Agent"),
+            ));
+            request
+                .respond(Response::from_string(body).with_status_code(status))
+                .unwrap();
+        }
+
+        seen
+    });
+
+    (format!("http://127.0.0.1:{port}"), handle)
+}
+
+/// Fetcher answering every day with the same input, counting how often it was asked.
+struct FakeFetcher(Rc<Cell<usize>>);
+
+impl Fetch for FakeFetcher {
+    fn fetch(&self, _day: u8) -> Result<String> {
+        self.0.set(self.0.get() + 1);
+
+        Ok("fetched\n".to_owned())
+    }
+}
+
+#[test]
+fn http_fetcher_sends_session_and_user_agent() {
+    let (url, server) = stub_server(vec![(200, "1000\n2000\n")]);
+    let fetcher = HttpFetcher::new(Client::new(&format!("{url}/"), "secret", "aoc-tests"));
+
+    let input = fetcher.fetch(7).unwrap();
+    let seen = server.join().unwrap();
+
+    assert_eq!(input, "1000\n2000\n");
+    assert_eq!(
+        seen,
+        [(
+            "/2022/day/7/input".to_owned(),
+            Some("session=secret".to_owned()),
+            Some("aoc-tests".to_owned())
+        )]
+    );
+}
+
+#[test]
+fn http_fetcher_reports_locked_days() {
+    let (url, server) = stub_server(vec![(404, "Not Found")]);
+    let fetcher = HttpFetcher::new(Client::new(&url, "secret", "aoc-tests"));
+
+    let error = fetcher.fetch(25).unwrap_err();
+    server.join().unwrap();
+
+    assert!(error.to_string().contains("not unlocked"), "{error}");
+}
+
+#[test]
+fn missing_inputs_are_fetched_once_into_the_cache() {
+    let dir = TempDir::new("fetch-once");
+    let calls = Rc::new(Cell::new(0));
+    let fetcher = FakeFetcher(calls.clone());
+    let store = InputStore::new(
+        dir.join("input"),
+        Some(dir.join("cache")),
+        Some(Box::new(fetcher)),
+    );
+
+    let first = store.resolve(3).unwrap().unwrap();
+    let second = store.resolve(3).unwrap().unwrap();
+
+    assert_eq!(first, dir.join("cache/d03.txt"));
+    assert_eq!(second, first);
+    assert_eq!(fs::read_to_string(&first).unwrap(), "fetched\n");
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn local_inputs_take_precedence() {
+    let dir = TempDir::new("local-first");
+    fs::create_dir_all(dir.join("input")).unwrap();
+    fs::write(dir.join("input/d05.txt"), "local\n").unwrap();
+    let calls = Rc::new(Cell::new(0));
+    let fetcher = FakeFetcher(calls.clone());
+    let store = InputStore::new(
+        dir.join("input"),
+        Some(dir.join("cache")),
+        Some(Box::new(fetcher)),
+    );
+
+    assert_eq!(store.resolve(5).unwrap(), Some(dir.join("input/d05.txt")));
+    assert_eq!(calls.get(), 0);
+}
+
+#[test]
+fn nothing_is_found_without_a_fetcher() {
+    let dir = TempDir::new("no-fetcher");
+    let store = InputStore::new(dir.join("input"), Some(dir.join("cache")), None);
+
+    assert_eq!(store.resolve(1).unwrap(), None);
+    assert!(!store.can_fetch());
+}
@@ -0,0 +1,266 @@
+use aoc2022_rust::days::{self, d14, d15};
+use aoc2022_rust::{DynSolution, Part, Solution};
+
+fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+        .collect()
+}
+
+fn solve(solution: &dyn DynSolution, input: &str, part: Part) -> String {
+    let parsed = solution.parse_dyn(input).unwrap();
+
+    solution.solve_dyn(parsed.as_ref(), part).unwrap()
+}
+
+#[test]
+fn params_list_the_defaults() {
+    assert_eq!(
+        d15::Day15::DEFAULT.params(),
+        [
+            ("row", d15::SCANNED_ROW.to_string()),
+            ("max", d15::MAX_COORD.to_string())
+        ]
+    );
+    assert_eq!(
+        d14::Day14::DEFAULT.params(),
+        [("source", "500,0".to_owned())]
+    );
+}
+
+#[test]
+fn with_params_changes_only_the_copy() {
+    let input = "Sensor at x=8, y=7: closest beacon is at x=2, y=10\n";
+    let day15 = days::get(15).unwrap();
+    let configured = day15.with_params(&params(&[("row", "10")])).unwrap();
+
+    assert_eq!(solve(configured.as_ref(), input, Part::One), "12");
+    assert_eq!(solve(day15, input, Part::One), "0");
+}
+
+#[test]
+fn later_params_win() {
+    let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n";
+    let day06 = days::get(6).unwrap();
+    let configured = day06
+        .with_params(&params(&[("packet", "14"), ("packet", "5")]))
+        .unwrap();
+
+    assert_eq!(solve(configured.as_ref(), input, Part::One), "8");
+}
+
+#[test]
+fn unknown_params_are_rejected() {
+    let error = days::get(15)
+        .unwrap()
+        .with_params(&params(&[("rows", "10")]))
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "Day 15 has no parameter `rows`, expected one of: row, max"
+    );
+
+    let error = days::get(1)
+        .unwrap()
+        .with_params(&params(&[("elves", "3")]))
+        .err()
+        .unwrap();
+    assert_eq!(error.to_string(), "Day 1 has no parameters");
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let cases = [
+        (15, "row", "ten"),
+        (15, "row", "-10000001"),
+        (15, "max", "9223372036854775807"),
+        (9, "knots", "0"),
+        (11, "rounds2", "-1"),
+        (14, "source", "500"),
+        (14, "source", "500,x"),
+    ];
+
+    for (day, name, value) in cases {
+        let result = days::get(day)
+            .unwrap()
+            .with_params(&params(&[(name, value)]));
+
+        assert!(result.is_err(), "day {day} accepted {name}={value}");
+    }
+}
+
+#[test]
+fn variants_take_the_same_params() {
+    for variant in days::VARIANTS {
+        let main = days::get(variant.day()).unwrap();
+
+        assert_eq!(
+            variant.with_params(&[]).is_ok(),
+            main.with_params(&[]).is_ok()
+        );
+    }
+
+    assert_eq!(
+        d15::Day15Intervals::DEFAULT.params(),
+        d15::Day15::DEFAULT.params()
+    );
+    assert_eq!(
+        d14::Day14Memo::DEFAULT.params(),
+        d14::Day14::DEFAULT.params()
+    );
+}
+
+#[test]
+fn sand_source_can_be_anywhere() {
+    let cases = [
+        ("0,3 -> 4,3\n", "2,0", ["4", "17"]),
+        ("498,4 -> 498,6 -> 496,6\n", "5,0", ["0", "64"]),
+        ("498,4 -> 498,6 -> 496,6\n", "600,10", ["0", "4"]),
+    ];
+
+    for (input, source, answers) in cases {
+        for solution in days::implementations(14) {
+            let configured = solution
+                .with_params(&params(&[("source", source)]))
+                .unwrap();
+
+            for (part, answer) in Part::BOTH.into_iter().zip(answers) {
+                assert_eq!(
+                    solve(configured.as_ref(), input, part),
+                    answer,
+                    "{} part {part:?} with the source at {source}",
+                    solution.variant()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn disk_size_limits_the_update() {
+    let input = "$ cd /\n$ ls\ndir d\n40 a\n$ cd d\n$ ls\n60 b\n";
+    let day07 = days::get(7).unwrap();
+    let configure = |pairs: &[(&str, &str)]| day07.with_params(&params(pairs)).unwrap();
+
+    let small = configure(&[("disk", "150"), ("update", "100")]);
+    assert_eq!(solve(small.as_ref(), input, Part::Two), "60");
+
+    let roomy = configure(&[("update", "20000000")]);
+    assert_eq!(solve(roomy.as_ref(), input, Part::Two), "0");
+
+    for (pairs, reason) in [
+        (
+            [("disk", "1"), ("update", "0")],
+            "Files take up 100, more than the disk size 1",
+        ),
+        (
+            [("disk", "100"), ("update", "101")],
+            "Update of 101 does not fit on a disk of 100",
+        ),
+    ] {
+        let configured = configure(&pairs);
+        let parsed = configured.parse_dyn(input).unwrap();
+
+        assert_eq!(solve(configured.as_ref(), input, Part::One), "160");
+        let error = configured
+            .solve_dyn(parsed.as_ref(), Part::Two)
+            .unwrap_err();
+        assert_eq!(error.to_string(), reason);
+    }
+}
+
+/// A monkey throwing its single item to itself.
+const MONKEY: &str = "Monkey 0:\n  Starting items: 1\n  Operation: new = old + 1\n  \
+                      Test: divisible by 2\n    If true: throw to monkey 0\n    \
+                      If false: throw to monkey 0\n";
+
+#[test]
+fn inputs_without_an_answer_fail() {
+    let report = "Sensor at x=0, y=0: closest beacon is at x=0, y=0\n";
+    let cases = [
+        (
+            1,
+            "1\n\n2\n",
+            &[][..],
+            Part::Two,
+            "Fewer than three elves carry calories",
+        ),
+        (
+            3,
+            "abcb\nab\n",
+            &[],
+            Part::One,
+            "No item type is in both compartments of rucksack 2",
+        ),
+        (
+            3,
+            "abb\n",
+            &[],
+            Part::One,
+            "Compartments of rucksack 1 hold different numbers of items",
+        ),
+        (
+            3,
+            "aa\naa\n",
+            &[],
+            Part::Two,
+            "Rucksacks do not split into groups of three",
+        ),
+        (3, "aa\nbb\ncc\n", &[], Part::Two, "Group 1 has no badge"),
+        (
+            6,
+            "abab\n",
+            &[],
+            Part::One,
+            "No start-of-packet marker in the datastream",
+        ),
+        (
+            11,
+            MONKEY,
+            &[],
+            Part::One,
+            "Fewer than two monkeys to measure the business of",
+        ),
+        (
+            12,
+            "SbE\n",
+            &[],
+            Part::One,
+            "No way up from the start to the end",
+        ),
+        (
+            15,
+            report,
+            &[("max", "0")],
+            Part::Two,
+            "Could not find distress beacon",
+        ),
+    ];
+
+    for (day, input, pairs, part, reason) in cases {
+        let solution = days::get(day).unwrap().with_params(&params(pairs)).unwrap();
+        let parsed = solution.parse_dyn(input).unwrap();
+        let error = solution.solve_dyn(parsed.as_ref(), part).unwrap_err();
+
+        assert_eq!(error.to_string(), reason, "day {day}");
+    }
+}
+
+#[test]
+fn a_part_without_an_answer_leaves_the_other_alone() {
+    let cases = [
+        (1, "1\n\n2\n", Part::One, "2"),
+        (3, "aa\nbb\n", Part::One, "3"),
+        (3, "abc\nbcd\ncde\n", Part::Two, "3"),
+    ];
+
+    for (day, input, part, answer) in cases {
+        assert_eq!(
+            solve(days::get(day).unwrap(), input, part),
+            answer,
+            "day {day}"
+        );
+    }
+}
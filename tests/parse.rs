@@ -0,0 +1,206 @@
+use std::borrow::Cow;
+
+use aoc2022_rust::days;
+use aoc2022_rust::parse::{normalize, paragraphs};
+use aoc2022_rust::ParseError;
+
+#[test]
+fn normal_input_is_borrowed() {
+    assert!(matches!(normalize("1\n\n2\n"), Cow::Borrowed("1\n\n2\n")));
+    assert!(matches!(normalize(""), Cow::Borrowed("")));
+}
+
+#[test]
+fn foreign_line_endings_and_padding_are_normalized() {
+    assert_eq!(
+        normalize("\u{feff}1000\r\n2000 \r\n\r\n3000\t\r\n"),
+        "1000\n2000\n\n3000\n"
+    );
+    assert_eq!(normalize("1000\n2000"), "1000\n2000\n");
+    assert_eq!(normalize("1000\n\n\n  \n"), "1000\n");
+    assert_eq!(normalize("\n\n"), "");
+}
+
+#[test]
+fn leading_indentation_is_kept() {
+    assert_eq!(
+        normalize("    [D]    \r\n[N] [C]    \r\n"),
+        "    [D]\n[N] [C]\n"
+    );
+}
+
+#[test]
+fn paragraphs_are_separated_by_blank_lines() {
+    let blocks: Vec<_> = paragraphs("\n1\n2\n\n \n3\n\n").collect();
+
+    assert_eq!(blocks, ["1\n2", "3"]);
+}
+
+#[test]
+fn paragraphs_accept_windows_line_endings() {
+    let blocks: Vec<_> = paragraphs("1\r\n2\r\n\r\n3\r\n").collect();
+
+    assert_eq!(blocks, ["1\r\n2", "3"]);
+    assert_eq!(paragraphs("").count(), 0);
+    assert_eq!(paragraphs("\r\n \n").count(), 0);
+}
+
+#[test]
+fn paragraphs_point_into_the_input() {
+    let input = "1\n\n2\n3x\n";
+    let block = paragraphs(input).nth(1).unwrap();
+    let error = ParseError::at(input, &block[2..], "bad");
+
+    assert_eq!((error.line(), error.column()), (4, 1));
+}
+
+fn parse_error(day: u8, input: &str) -> ParseError {
+    let error = days::get(day).unwrap().parse_dyn(input).unwrap_err();
+
+    error.downcast().unwrap()
+}
+
+#[test]
+fn empty_input_is_rejected() {
+    for (day, reason) in [
+        (1, "expected calorie counts"),
+        (7, "expected a terminal transcript"),
+        (8, "expected a map of tree heights"),
+        (10, "expected a program"),
+        (11, "expected monkey notes"),
+        (14, "expected rock paths"),
+        (15, "expected sensor reports"),
+    ] {
+        assert_eq!(parse_error(day, "").reason(), reason, "day {day}");
+    }
+}
+
+#[test]
+fn transcripts_must_stay_below_the_root() {
+    let error = parse_error(7, "$ ls\n");
+    assert_eq!(
+        (error.line(), error.reason()),
+        (1, "expected `$ cd /` first")
+    );
+
+    let error = parse_error(7, "$ cd /\n$ cd a\n$ cd ..\n$ cd ..\n");
+    assert_eq!((error.line(), error.reason()), (4, "already at the root"));
+}
+
+#[test]
+fn stacks_must_be_numbered_in_order() {
+    let error = parse_error(5, "[A]\n 1   99999999999\n\nmove 1 from 1 to 2\n");
+
+    assert_eq!((error.line(), error.column()), (2, 6));
+    assert_eq!(error.reason(), "expected stack number 2");
+}
+
+#[test]
+fn moves_must_name_existing_stacks() {
+    let error = parse_error(5, "[A] [B]\n 1   2\n\nmove 1 from 1 to 3\n");
+
+    assert_eq!((error.line(), error.reason()), (4, "no such stack"));
+}
+
+#[test]
+fn moves_must_take_existing_crates() {
+    let error = parse_error(5, "[A]\n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\n");
+
+    assert_eq!(
+        (error.line(), error.reason()),
+        (5, "stack 2 only holds 1 crates")
+    );
+}
+
+#[test]
+fn addx_operands_must_be_bounded() {
+    let error = parse_error(10, "noop\naddx 9223372036854775807\naddx 1\n");
+
+    assert_eq!((error.line(), error.column()), (2, 6));
+    assert_eq!(
+        error.reason(),
+        "addx operand must be between -1000000 and 1000000"
+    );
+}
+
+#[test]
+fn monkeys_must_throw_to_existing_monkeys() {
+    let monkey = |target| {
+        format!(
+            "Monkey 0:\n  Starting items: 1\n  Operation: new = old + 1\n  Test: divisible by 2\n    \
+             If true: throw to monkey 1\n    If false: throw to monkey {target}\n"
+        )
+    };
+
+    let error = parse_error(11, &format!("{}\n{}", monkey(1), monkey(2)));
+    assert_eq!((error.line(), error.reason()), (13, "no such monkey"));
+
+    let zero = monkey(1).replace("by 2", "by 0");
+    let error = parse_error(11, &format!("{zero}\n{}", monkey(0)));
+    assert_eq!(
+        (error.line(), error.reason()),
+        (4, "cannot test divisibility by zero")
+    );
+}
+
+#[test]
+fn sensors_must_lie_within_reach() {
+    let report = "Sensor at x=0, y=0: closest beacon is at x=-3000000000000000000, y=3\n";
+    let error = parse_error(15, report);
+
+    assert_eq!((error.line(), error.column()), (1, 44));
+    assert_eq!(
+        error.reason(),
+        "coordinates must be between -10000000 and 10000000"
+    );
+}
+
+#[test]
+fn packets_must_be_well_formed() {
+    for (packet, column, reason) in [
+        ("[1,2", 5, "unclosed list"),
+        ("[", 2, "unclosed list"),
+        ("[[1]", 5, "unclosed list"),
+        ("[1]]", 4, "unexpected text after the packet"),
+        ("[1][2]", 4, "unexpected text after the packet"),
+        ("[1,,2]", 4, "empty element"),
+        ("[,1]", 2, "empty element"),
+        ("[1,]", 4, "empty element"),
+        ("[[1]2]", 5, "expected `,` or `]`"),
+        ("[1[2]]", 3, "expected `,` or `]`"),
+        ("1", 1, "packet must be a list"),
+    ] {
+        let error = parse_error(13, &format!("[3]\n{packet}\n"));
+
+        assert_eq!(
+            (error.line(), error.column(), error.reason()),
+            (2, column, reason),
+            "{packet}"
+        );
+    }
+}
+
+#[test]
+fn blueprint_costs_must_be_bounded() {
+    let blueprint =
+        "Blueprint 1: Each ore robot costs 4294967295 ore. Each clay robot costs 2 ore. \
+                     Each obsidian robot costs 3 ore and 14 clay. \
+                     Each geode robot costs 2 ore and 7 obsidian.\n";
+    let error = parse_error(19, blueprint);
+
+    assert_eq!(
+        (error.line(), error.reason()),
+        (1, "expected a blueprint with costs of at most 65535")
+    );
+}
+
+#[test]
+fn cubes_must_lie_in_the_scanned_space() {
+    let error = parse_error(18, "0,0,0\n30000,1,1\n");
+
+    assert_eq!((error.line(), error.column()), (2, 1));
+    assert_eq!(error.reason(), "coordinates must be between 0 and 99");
+
+    let error = parse_error(18, "1,-1,1\n");
+    assert_eq!(error.column(), 3);
+}
@@ -0,0 +1,272 @@
+//! Invariants every day has to keep on random valid inputs.
+
+mod generators;
+
+use itertools::Itertools;
+use proptest::prelude::*;
+
+use aoc2022_rust::days::*;
+use aoc2022_rust::Solution;
+
+/// Both answers for `input`, which has to be valid.
+fn solve<S: Solution>(solution: S, input: &str) -> (S::Answer1, S::Answer2) {
+    let parsed = solution.parse(input).unwrap();
+
+    (solution.part1(&parsed), solution.part2(&parsed))
+}
+
+proptest! {
+    #[test]
+    fn d01_top_three_carry_at_least_the_top_one(input in generators::calories()) {
+        let (top, top_three) = solve(d01::Day01, &input);
+        let top_three = top_three.unwrap();
+
+        prop_assert!(top <= top_three && top_three <= 3 * top);
+    }
+
+    #[test]
+    fn d02_every_round_scores_between_1_and_9(input in generators::strategy_guide()) {
+        let rounds = input.lines().count();
+        let (score1, score2) = solve(d02::Day02, &input);
+
+        prop_assert!((rounds..=9 * rounds).contains(&score1));
+        prop_assert!((rounds..=9 * rounds).contains(&score2));
+    }
+
+    #[test]
+    fn d03_priorities_are_between_1_and_52(input in generators::rucksacks()) {
+        let sacks = input.lines().count();
+        let (misplaced, badges) = solve(d03::Day03, &input);
+        let (misplaced, badges) = (misplaced.unwrap(), badges.unwrap());
+
+        prop_assert!((sacks..=52 * sacks).contains(&misplaced));
+        prop_assert!((sacks / 3..=52 * sacks / 3).contains(&badges));
+    }
+
+    #[test]
+    fn d04_containing_pairs_also_overlap(input in generators::section_pairs()) {
+        let (contained, overlapping) = solve(d04::Day04, &input);
+
+        prop_assert!(contained <= overlapping);
+        prop_assert!(overlapping <= input.lines().count());
+    }
+
+    #[test]
+    fn d05_moving_crates_one_by_one_is_the_old_crane(input in generators::crate_stacks()) {
+        let one_by_one = input
+            .lines()
+            .map(|line| match scan_move(line) {
+                Some((n, rest)) => format!("move 1{rest}\n").repeat(n),
+                None => format!("{line}\n"),
+            })
+            .collect::<String>();
+
+        let (old_crane, new_crane) = solve(d05::Day05, &input);
+        let (old_single, new_single) = solve(d05::Day05, &one_by_one);
+
+        prop_assert_eq!(new_crane.len(), old_crane.len());
+        prop_assert_eq!(&old_single, &old_crane);
+        prop_assert_eq!(&new_single, &old_crane);
+    }
+
+    #[test]
+    fn d06_message_markers_end_no_earlier_than_packet_markers(input in generators::datastream()) {
+        let (packet, message) = solve(d06::Day06::DEFAULT, &input);
+        let (packet, message) = (packet.unwrap(), message.unwrap());
+
+        prop_assert!(packet >= d06::SOP_MARKER_LEN);
+        prop_assert!(message >= d06::SOM_MARKER_LEN);
+        prop_assert!(packet <= message);
+    }
+
+    #[test]
+    fn d07_root_holds_every_file(input in generators::shell_transcript()) {
+        let files: usize = input
+            .lines()
+            .filter_map(|line| line.split_once(' ')?.0.parse::<usize>().ok())
+            .sum();
+
+        let lines = d07::Day07::DEFAULT.parse(&input).unwrap();
+        let usage = d07::disk_usage(&d07::traverse(&lines));
+        let freed = solve(d07::Day07::DEFAULT, &input).1.unwrap();
+        let to_free = d07::UPDATE_SIZE - (d07::DISK_SIZE - files);
+
+        prop_assert_eq!(usage["/"], files);
+        prop_assert!(usage.values().all(|&size| size <= files));
+        prop_assert!((to_free..=files).contains(&freed));
+    }
+
+    #[test]
+    fn d08_edge_trees_are_visible(input in generators::tree_grid()) {
+        let width = input.lines().next().unwrap().len();
+        let height = input.lines().count();
+        let (visible, _) = solve(d08::Day08, &input);
+
+        prop_assert!(visible >= 2 * (width + height) - 4);
+        prop_assert!(visible <= width * height);
+    }
+
+    #[test]
+    fn d09_long_rope_tail_visits_no_more_than_the_short_one(input in generators::rope_moves()) {
+        let (short, long) = solve(d09::Day09::DEFAULT, &input);
+
+        prop_assert!(long >= 1);
+        prop_assert!(long <= short);
+    }
+
+    #[test]
+    fn d10_instructions_after_the_last_cycle_do_nothing(input in generators::program()) {
+        let (signal, picture) = solve(d10::Day10, &input);
+        let (longer_signal, longer_picture) = solve(d10::Day10, &format!("{input}addx 7\n"));
+
+        prop_assert_eq!(signal, longer_signal);
+        prop_assert_eq!(&picture, &longer_picture);
+        prop_assert_eq!(picture.lines().count(), d10::ROWS);
+        prop_assert!(picture.lines().all(|row| row.chars().count() == d10::COLS));
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn d11_monkeys_inspect_every_item_each_round(input in generators::monkeys()) {
+        let mut monkeys = d11::Day11::DEFAULT.parse(&input).unwrap();
+        let items: usize = monkeys.iter().map(|m| m.items.len()).sum();
+        let modulus: u64 = monkeys.iter().map(|m| m.test).product();
+
+        for _ in 0..d11::SHORT_N_ROUNDS {
+            let inspected = d11::play_single_round(&mut monkeys, |worry| worry % modulus).unwrap();
+
+            prop_assert!(inspected.iter().sum::<usize>() >= items);
+            prop_assert!(inspected.iter().sum::<usize>() <= items * monkeys.len());
+            prop_assert_eq!(monkeys.iter().map(|m| m.items.len()).sum::<usize>(), items);
+        }
+    }
+
+    #[test]
+    fn d12_hiking_trail_is_no_longer_than_the_climb(input in generators::heightmap()) {
+        let (climb, trail) = solve(d12::Day12, &input);
+        let (climb, trail) = (climb.unwrap(), trail.unwrap());
+
+        prop_assert!(trail <= climb);
+        prop_assert!(climb >= 25);
+    }
+
+    #[test]
+    fn d13_swapping_packets_flips_the_order(input in generators::packet_pairs()) {
+        let swapped = input
+            .split("\n\n")
+            .map(|pair| pair.lines().rev().map(|line| format!("{line}\n")).collect::<String>())
+            .join("\n");
+        let equal: usize = input
+            .split("\n\n")
+            .enumerate()
+            .filter(|(_, pair)| {
+                let (a, b) = pair.lines().map(|p| d13::parse_packet(p).unwrap()).collect_tuple().unwrap();
+                d13::compare(&a, &b).is_eq()
+            })
+            .map(|(i, _)| i + 1)
+            .sum();
+        let pairs = input.split("\n\n").count();
+
+        let (ordered, decoder) = solve(d13::Day13, &input);
+        let (ordered_swapped, _) = solve(d13::Day13, &swapped);
+
+        prop_assert_eq!(ordered + ordered_swapped, pairs * (pairs + 1) / 2 + equal);
+        prop_assert!(decoder >= 2);
+    }
+
+    #[test]
+    fn d14_a_floor_catches_more_sand_than_the_abyss(input in generators::rock_paths()) {
+        let (abyss, floor) = solve(d14::Day14::DEFAULT, &input);
+
+        prop_assert!(floor > abyss);
+    }
+
+    #[test]
+    fn d15_scanned_row_matches_a_brute_force_count(
+        input in generators::sensor_reports(d15::SCANNED_ROW),
+    ) {
+        let report = d15::Day15::DEFAULT.parse(&input).unwrap();
+        let row = d15::SCANNED_ROW;
+        let expected = (-200..=200)
+            .filter(|&x| {
+                let covered = report
+                    .iter()
+                    .any(|&(s, b)| d15::manhattan(s, (x, row)) <= d15::manhattan(s, b));
+                covered && !report.iter().any(|&(_, b)| b == (x, row))
+            })
+            .count();
+
+        prop_assert_eq!(d15::Day15::DEFAULT.part1(&report), expected);
+    }
+
+    #[test]
+    fn d17_skipping_cycles_matches_dropping_every_rock(
+        input in generators::jet_pattern(),
+        rocks in 0..3000u64,
+    ) {
+        let jets = d17::Day17::DEFAULT.parse(&input).unwrap();
+        let mut chamber = d17::Chamber::new(&jets);
+        while chamber.dropped() < rocks {
+            chamber.drop_rock();
+        }
+
+        prop_assert_eq!(d17::tower_height(&jets, rocks), chamber.height() as u64);
+    }
+
+    #[test]
+    fn d18_air_pockets_only_hide_faces(input in generators::lava_cubes()) {
+        let cubes = input.lines().count();
+        let (surface, exterior) = solve(d18::Day18, &input);
+
+        prop_assert!(exterior <= surface && surface <= 6 * cubes);
+        prop_assert_eq!(surface % 2, 0);
+    }
+
+    #[test]
+    fn d19_more_time_never_cracks_fewer_geodes(input in generators::blueprints()) {
+        let blueprints = d19::Day19::DEFAULT.parse(&input).unwrap();
+
+        for blueprint in &blueprints {
+            let shorter = d19::max_geodes(blueprint, 18);
+            let longer = d19::max_geodes(blueprint, 22);
+
+            prop_assert!(shorter <= longer, "{shorter} geodes in 18 minutes, {longer} in 22");
+        }
+    }
+
+    #[test]
+    fn d20_grove_coordinates_are_three_of_the_numbers(input in generators::encrypted_file()) {
+        let largest = input.lines().map(|n| n.parse::<i64>().unwrap().abs()).max().unwrap();
+        let (coordinates, decrypted) = solve(d20::Day20::DEFAULT, &input);
+
+        prop_assert!(coordinates.abs() <= 3 * largest);
+        prop_assert!(decrypted.abs() <= 3 * largest * i64::from(d20::DECRYPTION_KEY));
+    }
+}
+
+proptest! {
+    // searching a dozen valves takes up to a second without optimizations
+    #![proptest_config(ProptestConfig::with_cases(if cfg!(debug_assertions) { 16 } else { 64 }))]
+
+    #[test]
+    fn d16_an_elephant_never_releases_less(input in generators::valve_network()) {
+        let minutes = d16::TIME_WITH_ELEPHANT;
+        let day = d16::Day16 { minutes1: minutes, minutes2: minutes };
+        let (alone, in_pairs) = solve(day, &input);
+        let (longer, _) = solve(d16::Day16::DEFAULT, &input);
+
+        prop_assert!(in_pairs >= alone);
+        prop_assert!(longer >= alone);
+    }
+}
+
+/// Crate count and the rest of a day 5 move, e.g. `(3, " from 1 to 2")`.
+fn scan_move(line: &str) -> Option<(usize, &str)> {
+    let rest = line.strip_prefix("move ")?;
+    let end = rest.find(' ')?;
+
+    Some((rest[..end].parse().ok()?, &rest[end..]))
+}
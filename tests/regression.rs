@@ -0,0 +1,182 @@
+//! Checks every solution against recorded answers.
+//!
+//! Each directory holds `dXX.txt` inputs next to an `answers.toml` manifest with a `[dXX]` table
+//! per input, giving the expected `part1` and `part2` answers and, in a `params` table, any
+//! puzzle constants that differ from the defaults of the day. `tests/examples` has the worked
+//! examples from the puzzle texts, `input/answers.toml` is optional and covers the real inputs,
+//! which may be encrypted as `dXX.txt.enc`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc2022_rust::{days, input, vault, Part};
+
+fn repo_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/// Answers compare equal regardless of trailing whitespace, which the CRT picture of day 10 has.
+fn normalize(answer: &str) -> String {
+    let lines: Vec<_> = answer.lines().map(str::trim_end).collect();
+
+    lines.join("\n").trim_matches('\n').to_owned()
+}
+
+fn expected_answer(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Integer(n) => n.to_string(),
+        other => panic!("Answers and parameters must be strings or integers, got {other}"),
+    }
+}
+
+/// Parameter overrides in the `params` table of a day, e.g. `params = { row = 10 }`.
+fn params(answers: &toml::Value) -> Vec<(String, String)> {
+    let Some(params) = answers.get("params") else {
+        return vec![];
+    };
+
+    params
+        .as_table()
+        .unwrap_or_else(|| panic!("`params` must be a table, got {params}"))
+        .iter()
+        .map(|(name, value)| (name.clone(), expected_answer(value)))
+        .collect()
+}
+
+/// Runs every day listed in `dir/answers.toml`, returning how many answers were checked and a
+/// description of every mismatch.
+///
+/// Inputs missing from `dir` are skipped when `skip_missing` is set and reported otherwise.
+fn check_answers(dir: &Path, skip_missing: bool) -> (usize, Vec<String>) {
+    let manifest_path = dir.join("answers.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .unwrap_or_else(|e| panic!("Cannot read {}: {e}", manifest_path.display()));
+    let manifest: toml::Table = manifest
+        .parse()
+        .unwrap_or_else(|e| panic!("Invalid {}: {e}", manifest_path.display()));
+
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for (key, answers) in &manifest {
+        let day = key
+            .strip_prefix('d')
+            .and_then(|d| d.parse().ok())
+            .unwrap_or_else(|| panic!("Manifest keys must look like `dXX`, got {key:?}"));
+        let solution = days::get(day)
+            .unwrap_or_else(|| panic!("No solution for day {day}"))
+            .with_params(&params(answers))
+            .unwrap_or_else(|e| panic!("Invalid params for day {day}: {e}"));
+
+        let mut input_path = dir.join(format!("{key}.txt"));
+        if !input_path.exists() && vault::encrypted_path(&input_path).exists() {
+            input_path = vault::encrypted_path(&input_path);
+        }
+
+        if skip_missing && !input_path.exists() {
+            continue;
+        }
+
+        let input = match input::read(&input_path) {
+            Ok(input) => input,
+            Err(e) => {
+                failures.push(format!("{e:#}"));
+                continue;
+            }
+        };
+
+        let parsed = match solution.parse_dyn(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                failures.push(format!("{}: {e}", input_path.display()));
+                continue;
+            }
+        };
+
+        for part in Part::BOTH {
+            let Some(expected) = answers.get(format!("part{part}")) else {
+                continue;
+            };
+            let expected = expected_answer(expected);
+            let actual = match solution.solve_dyn(parsed.as_ref(), part) {
+                Ok(actual) => actual,
+                Err(e) => {
+                    failures.push(format!(
+                        "{}: day {day} part {part}: {e:#}",
+                        input_path.display()
+                    ));
+                    continue;
+                }
+            };
+
+            if normalize(&actual) != normalize(&expected) {
+                failures.push(format!(
+                    "{}: day {day} part {part}: expected\n{expected}\ngot\n{actual}",
+                    input_path.display()
+                ));
+            }
+
+            checked += 1;
+        }
+    }
+
+    (checked, failures)
+}
+
+#[test]
+fn examples() {
+    let (checked, failures) = check_answers(&repo_path("tests/examples"), false);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+    assert!(checked > 0, "No example answers were checked");
+}
+
+/// Saving an example on Windows must not change its answers.
+#[test]
+fn examples_with_windows_line_endings() {
+    for solution in days::ALL {
+        let path = repo_path(&format!("tests/examples/d{:02}.txt", solution.day()));
+        let input = fs::read_to_string(&path).unwrap();
+        let mangled = format!(
+            "\u{feff}{}\r\n\r\n",
+            input.trim_end().replace('\n', " \r\n")
+        );
+
+        let expected = solution.parse_dyn(&input).unwrap();
+        let actual = solution
+            .parse_dyn(&mangled)
+            .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+
+        for part in Part::BOTH {
+            assert_eq!(
+                solution.solve_dyn(actual.as_ref(), part).unwrap(),
+                solution.solve_dyn(expected.as_ref(), part).unwrap(),
+                "{} part {part}",
+                path.display()
+            );
+        }
+    }
+}
+
+#[test]
+fn every_day_has_an_example() {
+    for solution in days::ALL {
+        let path = repo_path(&format!("tests/examples/d{:02}.txt", solution.day()));
+
+        assert!(path.exists(), "Missing example {}", path.display());
+    }
+}
+
+#[test]
+fn stored_inputs() {
+    let dir = repo_path("input");
+
+    if !dir.join("answers.toml").exists() {
+        return;
+    }
+
+    let (_, failures) = check_answers(&dir, true);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
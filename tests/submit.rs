@@ -0,0 +1,182 @@
+mod common;
+
+use std::cell::Cell;
+use std::thread;
+
+use anyhow::Result;
+use tiny_http::{Response, Server};
+
+use aoc2022_rust::client::Client;
+use aoc2022_rust::submit::{
+    parse_response, HttpSubmitter, Outcome, SubmissionLog, Submit, Verdict,
+};
+use aoc2022_rust::Part;
+
+use common::TempDir;
+
+fn page(message: &str) -> String {
+    format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+}
+
+/// Submitter always giving the same outcome, counting how often it was asked.
+struct FakeSubmitter {
+    outcome: Outcome,
+    calls: Cell<usize>,
+}
+
+impl FakeSubmitter {
+    fn new(outcome: Outcome) -> Self {
+        Self {
+            outcome,
+            calls: Cell::new(0),
+        }
+    }
+}
+
+impl Submit for FakeSubmitter {
+    fn submit(&self, _day: u8, _part: Part, _answer: &str) -> Result<Outcome> {
+        self.calls.set(self.calls.get() + 1);
+        Ok(self.outcome.clone())
+    }
+}
+
+#[test]
+fn responses_are_recognized() {
+    let cases = [
+        (
+            "That's the right answer! You are <em>one gold star</em> closer.",
+            Outcome::Correct,
+        ),
+        (
+            "That's not the right answer. If you're stuck, make sure you're using the full input.",
+            Outcome::Incorrect,
+        ),
+        (
+            "That's not the right answer; your answer is too high. Please wait one minute.",
+            Outcome::TooHigh,
+        ),
+        (
+            "That's not the right answer; your answer is too low. Please wait one minute.",
+            Outcome::TooLow,
+        ),
+        (
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again. You have 1m 2s left to wait.",
+            Outcome::RateLimited(Some("1m 2s left to wait".to_owned())),
+        ),
+        (
+            "You don't seem to be solving the right level. Did you already complete it?",
+            Outcome::WrongLevel,
+        ),
+        (
+            "Something else",
+            Outcome::Unknown("Something else".to_owned()),
+        ),
+    ];
+
+    for (message, expected) in cases {
+        assert_eq!(parse_response(&page(message)), expected, "{message}");
+    }
+}
+
+#[test]
+fn only_judged_outcomes_are_final() {
+    assert!(Outcome::TooLow.is_final());
+    assert!(!Outcome::RateLimited(None).is_final());
+    assert!(!Outcome::WrongLevel.is_final());
+}
+
+#[test]
+fn http_submitter_posts_the_answer_form() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let port = server.server_addr().to_ip().unwrap().port();
+
+    let handle = thread::spawn(move || {
+        let mut request = server.recv().unwrap();
+        let mut body = String::new();
+        request.as_reader().read_to_string(&mut body).unwrap();
+        let cookie = request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv("Cookie"))
+            .map(|h| h.value.to_string());
+        let seen = (
+            request.method().to_string(),
+            request.url().to_owned(),
+            cookie,
+            body,
+        );
+
+        request
+            .respond(Response::from_string(page("That's the right answer!")))
+            .unwrap();
+
+        seen
+    });
+
+    let client = Client::new(&format!("http://127.0.0.1:{port}"), "token", "tests");
+    let outcome = HttpSubmitter::new(client)
+        .submit(7, Part::Two, "24933642")
+        .unwrap();
+
+    assert_eq!(outcome, Outcome::Correct);
+    assert_eq!(
+        handle.join().unwrap(),
+        (
+            "POST".to_owned(),
+            "/2022/day/7/answer".to_owned(),
+            Some("session=token".to_owned()),
+            "level=2&answer=24933642".to_owned(),
+        )
+    );
+}
+
+#[test]
+fn judged_answers_are_not_sent_twice() {
+    let dir = TempDir::new("repeated");
+    let path = dir.join("submissions.jsonl");
+    let submitter = FakeSubmitter::new(Outcome::TooHigh);
+
+    let mut log = SubmissionLog::open(&path).unwrap();
+    let first = log.submit(&submitter, 1, Part::One, "100").unwrap();
+    assert_eq!(first, Verdict::Sent(Outcome::TooHigh));
+
+    let mut reopened = SubmissionLog::open(&path).unwrap();
+    let second = reopened.submit(&submitter, 1, Part::One, "100").unwrap();
+    assert_eq!(second, Verdict::Repeated(Outcome::TooHigh));
+    assert_eq!(submitter.calls.get(), 1);
+
+    reopened.submit(&submitter, 1, Part::One, "99").unwrap();
+    assert_eq!(submitter.calls.get(), 2);
+    assert_eq!(reopened.submissions().len(), 2);
+}
+
+#[test]
+fn solved_parts_are_not_submitted_again() {
+    let dir = TempDir::new("solved");
+    let path = dir.join("submissions.jsonl");
+    let correct = FakeSubmitter::new(Outcome::Correct);
+    let mut log = SubmissionLog::open(&path).unwrap();
+
+    log.submit(&correct, 2, Part::One, "15").unwrap();
+    let verdict = log.submit(&correct, 2, Part::One, "16").unwrap();
+
+    assert_eq!(verdict, Verdict::Solved("15".to_owned()));
+    assert_eq!(correct.calls.get(), 1);
+    assert_eq!(log.solution(2, Part::Two), None);
+}
+
+#[test]
+fn rate_limited_answers_are_not_recorded() {
+    let dir = TempDir::new("rate-limited");
+    let path = dir.join("submissions.jsonl");
+    let submitter = FakeSubmitter::new(Outcome::RateLimited(None));
+    let mut log = SubmissionLog::open(&path).unwrap();
+
+    log.submit(&submitter, 3, Part::One, "157").unwrap();
+    log.submit(&submitter, 3, Part::One, "157").unwrap();
+
+    assert_eq!(submitter.calls.get(), 2);
+    assert!(log.submissions().is_empty());
+    assert!(!path.exists());
+}
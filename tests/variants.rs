@@ -0,0 +1,76 @@
+//! Every alternative implementation of a day has to give the same answers as the main one.
+//!
+//! Variants are checked on the worked examples and on random valid inputs, where `proptest`
+//! shrinks any disagreement to the smallest diverging input it can find.
+
+mod generators;
+
+use std::fs;
+use std::path::Path;
+
+use proptest::prelude::*;
+
+use aoc2022_rust::cross_check::cross_check;
+use aoc2022_rust::days::{self, d15};
+use aoc2022_rust::Part;
+
+fn example(day: u8) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("tests/examples/d{day:02}.txt"));
+
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("Cannot read {}: {e}", path.display()))
+}
+
+#[test]
+fn variants_agree_on_examples() {
+    for variant in days::VARIANTS {
+        let divergences =
+            cross_check(variant.day(), &example(variant.day()), &Part::BOTH, &[]).unwrap();
+
+        assert_eq!(divergences, vec![], "day {}", variant.day());
+    }
+}
+
+#[test]
+fn variants_are_registered_after_a_main_solution() {
+    for variant in days::VARIANTS {
+        let implementations = days::implementations(variant.day());
+
+        assert_eq!(implementations[0].variant(), "default");
+        assert_ne!(variant.variant(), "default");
+    }
+}
+
+#[test]
+fn sand_can_pile_up_to_the_source_before_reaching_the_abyss() {
+    for solution in days::implementations(14) {
+        let parsed = solution.parse_dyn("518,2 -> 451,2\n").unwrap();
+        let answer = solution.solve_dyn(parsed.as_ref(), Part::One).unwrap();
+
+        assert_eq!(answer, "4", "{}", solution.variant());
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn d08_variants_agree(input in generators::tree_grid()) {
+        prop_assert_eq!(cross_check(8, &input, &Part::BOTH, &[]).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn d14_variants_agree(input in generators::rock_paths()) {
+        prop_assert_eq!(cross_check(14, &input, &Part::BOTH, &[]).unwrap(), vec![]);
+    }
+
+    // searching every row for a distress beacon takes too long on inputs without one
+    #[test]
+    fn d15_variants_agree(input in generators::sensor_reports(d15::SCANNED_ROW)) {
+        prop_assert_eq!(cross_check(15, &input, &[Part::One], &[]).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn d20_variants_agree(input in generators::encrypted_file()) {
+        prop_assert_eq!(cross_check(20, &input, &Part::BOTH, &[]).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use std::fs;
+
+use aoc2022_rust::input::{self, InputStore};
+use aoc2022_rust::vault::{self, Key};
+
+use common::TempDir;
+
+#[test]
+fn encrypted_inputs_round_trip() {
+    let key = Key::generate();
+    let encrypted = key.encrypt("1000\n2000\n");
+
+    assert!(!encrypted.windows(4).any(|w| w == b"1000"));
+    assert_eq!(key.decrypt(&encrypted).unwrap(), "1000\n2000\n");
+}
+
+#[test]
+fn wrong_keys_and_tampering_are_detected() {
+    let key = Key::generate();
+    let mut encrypted = key.encrypt("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+    assert!(Key::generate().decrypt(&encrypted).is_err());
+
+    *encrypted.last_mut().unwrap() ^= 1;
+    assert!(key.decrypt(&encrypted).is_err());
+    assert!(key.decrypt(b"plain text").is_err());
+}
+
+#[test]
+fn keys_survive_the_key_file() {
+    let dir = TempDir::new("vault-key");
+    let path = dir.join("keys").join("input.key");
+    let key = Key::generate();
+
+    key.save(&path).unwrap();
+    let loaded = Key::load(&path).unwrap();
+
+    assert_eq!(loaded.to_hex(), key.to_hex());
+    assert!(Key::from_hex("abc").is_err());
+    assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+}
+
+#[test]
+fn files_are_encrypted_next_to_the_plain_ones() {
+    let dir = TempDir::new("vault-files");
+    let plain = dir.join("d01.txt");
+    fs::write(&plain, "1000\n").unwrap();
+    let key = Key::generate();
+
+    let encrypted = vault::encrypt_file(&key, &plain).unwrap();
+    assert_eq!(encrypted, dir.join("d01.txt.enc"));
+
+    fs::remove_file(&plain).unwrap();
+    assert_eq!(vault::decrypt_file(&key, &encrypted).unwrap(), plain);
+    assert_eq!(fs::read_to_string(&plain).unwrap(), "1000\n");
+}
+
+#[test]
+fn rotating_the_key_re_encrypts_every_file() {
+    let dir = TempDir::new("vault-rotate");
+    let key_path = dir.join("input.key");
+    let old_key = Key::generate();
+    old_key.save(&key_path).unwrap();
+
+    for (name, contents) in [("d01.txt", "1000\n"), ("d02.txt", "A Y\n")] {
+        fs::write(dir.join(name), contents).unwrap();
+        vault::encrypt_file(&old_key, &dir.join(name)).unwrap();
+    }
+
+    let rotated = vault::rotate_key(&key_path, &dir).unwrap();
+    let new_key = Key::load(&key_path).unwrap();
+
+    assert_eq!(rotated, [dir.join("d01.txt.enc"), dir.join("d02.txt.enc")]);
+    assert_ne!(new_key.to_hex(), old_key.to_hex());
+    assert!(vault::read(&old_key, &rotated[0]).is_err());
+    assert_eq!(vault::read(&new_key, &rotated[1]).unwrap(), "A Y\n");
+    assert!(!dir.join("input.key.old").exists());
+}
+
+#[test]
+fn stores_read_encrypted_inputs_transparently() {
+    let dir = TempDir::new("vault-store");
+    let local = dir.join("input");
+    fs::create_dir_all(&local).unwrap();
+    let key_path = dir.join("input.key");
+    let key = Key::generate();
+    key.save(&key_path).unwrap();
+    fs::write(
+        local.join("d06.txt.enc"),
+        key.encrypt("bvwbjplbgvbhsrlpgdmjqwftvncz"),
+    )
+    .unwrap();
+
+    std::env::set_var("AOC_INPUT_KEY", &key_path);
+    let store = InputStore::new(&local, None, None);
+    let path = store.find(6).unwrap();
+
+    assert_eq!(path, local.join("d06.txt.enc"));
+    assert_eq!(input::read(&path).unwrap(), "bvwbjplbgvbhsrlpgdmjqwftvncz");
+
+    fs::write(local.join("d06.txt"), "nppdvjthqldpwncqszvftbrmjlhg").unwrap();
+    assert_eq!(store.find(6).unwrap(), local.join("d06.txt"));
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use std::fs;
+use std::slice;
+use std::time::Duration;
+
+use aoc2022_rust::watch::{answer_change, time_change, FileWatcher};
+
+use common::TempDir;
+
+/// Long enough for the notification to arrive on a busy machine.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[test]
+fn time_changes() {
+    let ms = Duration::from_millis;
+
+    assert_eq!(time_change(None, ms(3)), "3.0ms");
+    assert_eq!(time_change(Some(ms(2)), ms(3)), "3.0ms (+1.0ms)");
+    assert_eq!(time_change(Some(ms(5)), ms(3)), "3.0ms (-2.0ms)");
+}
+
+#[test]
+fn answer_changes() {
+    assert_eq!(answer_change(None, "31"), None);
+    assert_eq!(answer_change(Some("31"), "31").unwrap(), "unchanged");
+    assert_eq!(answer_change(Some("29"), "31").unwrap(), "was 29");
+    assert_eq!(answer_change(Some("#.\n.#"), "##\n.#").unwrap(), "changed");
+}
+
+#[test]
+fn writing_a_watched_file() {
+    let dir = TempDir::new("watch-write");
+    let input = dir.join("d01.txt");
+    fs::write(&input, "1\n").unwrap();
+
+    let watcher = FileWatcher::new(slice::from_ref(&input), &[]).unwrap();
+    fs::write(&input, "2\n").unwrap();
+
+    assert_eq!(watcher.wait_timeout(TIMEOUT).unwrap(), Some(vec![input]));
+}
+
+#[test]
+fn replacing_a_watched_file() {
+    let dir = TempDir::new("watch-replace");
+    let input = dir.join("d01.txt");
+    let replacement = dir.join("d01.txt.new");
+    fs::write(&input, "1\n").unwrap();
+
+    let watcher = FileWatcher::new(slice::from_ref(&input), &[]).unwrap();
+    fs::write(&replacement, "2\n").unwrap();
+    fs::rename(&replacement, &input).unwrap();
+
+    assert_eq!(watcher.wait_timeout(TIMEOUT).unwrap(), Some(vec![input]));
+}
+
+#[test]
+fn other_files_next_to_a_watched_one_are_ignored() {
+    let dir = TempDir::new("watch-ignore");
+    let input = dir.join("d01.txt");
+    fs::write(&input, "1\n").unwrap();
+
+    let watcher = FileWatcher::new(slice::from_ref(&input), &[]).unwrap();
+    fs::write(dir.join("d02.txt"), "A X\n").unwrap();
+    let _ = fs::read_to_string(&input).unwrap();
+
+    assert_eq!(
+        watcher.wait_timeout(Duration::from_millis(500)).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn anything_under_a_watched_directory() {
+    let dir = TempDir::new("watch-dir");
+    let nested = dir.join("src/days");
+    fs::create_dir_all(&nested).unwrap();
+
+    let watcher = FileWatcher::new(&[], &[dir.join("src")]).unwrap();
+    fs::write(nested.join("d16.rs"), "").unwrap();
+
+    let changed = watcher.wait_timeout(TIMEOUT).unwrap().unwrap();
+    assert!(changed.contains(&nested.join("d16.rs")), "{changed:?}");
+}